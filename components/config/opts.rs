@@ -224,6 +224,10 @@ pub struct DebugOptions {
     /// Load web fonts synchronously to avoid non-deterministic network-driven reflows.
     pub load_webfonts_synchronously: bool,
 
+    /// Place list item markers against the list item's border box as in CSS 2.1, instead of
+    /// flowing them around floats (Layout 2013).
+    pub markers_ignore_floats: bool,
+
     /// Show webrender profiling stats on screen.
     pub webrender_stats: bool,
 
@@ -256,6 +260,7 @@ impl DebugOptions {
                 "dump-style-tree" => self.dump_style_tree = true,
                 "gc-profile" => self.gc_profile = true,
                 "load-webfonts-synchronously" => self.load_webfonts_synchronously = true,
+                "markers-ignore-floats" => self.markers_ignore_floats = true,
                 "precache-shaders" => self.precache_shaders = true,
                 "profile-script-events" => self.profile_script_events = true,
                 "relayout-event" => self.relayout_event = true,
@@ -339,6 +344,10 @@ impl DebugOptions {
             "load-webfonts-synchronously",
             "Load web fonts synchronously to avoid non-deterministic network-driven reflows",
         );
+        print_option(
+            "markers-ignore-floats",
+            "Place list item markers without regard to floats (Layout 2013).",
+        );
         print_option(
             "parallel-display-list-building",
             "Build display lists in parallel.",
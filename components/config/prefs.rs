@@ -457,6 +457,25 @@ mod gen {
                     enabled: bool,
                 },
                 legacy_layout: bool,
+                list_markers: {
+                    /// Whether outside markers hang from the list item's content box instead of
+                    /// its border box, putting its inline-start padding and border between them.
+                    #[serde(default)]
+                    anchor_to_content_box: bool,
+                },
+                list_numbers: {
+                    /// Whether `decimal` list item numbers are grouped with `,` separators.
+                    #[serde(default)]
+                    group_digits: bool,
+                    /// Whether `<ol>` picks its default numbering from the language of its
+                    /// content, e.g. Arabic-Indic digits for `lang="ar"`.
+                    #[serde(default)]
+                    lang_defaults: bool,
+                    /// Whether numbered list item markers repeat a bullet once per ordinal, e.g.
+                    /// "•••" for the third item, instead of showing the number.
+                    #[serde(default)]
+                    repeat_symbols: bool,
+                },
                 tables: {
                     enabled: bool,
                 },
@@ -25,7 +25,7 @@ use unicode_script::Script;
 use webrender_api::FontInstanceKey;
 
 use crate::font_context::{FontContext, FontSource};
-use crate::font_template::FontTemplateDescriptor;
+use crate::font_template::{FontTemplateDescriptor, UnicodeRanges};
 use crate::platform::font::{FontHandle, FontTable};
 use crate::platform::font_context::FontContextHandle;
 pub use crate::platform::font_list::fallback_font_families;
@@ -174,6 +174,8 @@ pub struct Font {
     shape_cache: RefCell<HashMap<ShapeCacheEntry, Arc<GlyphStore>>>,
     glyph_advance_cache: RefCell<HashMap<u32, FractionalPixel>>,
     pub font_key: FontInstanceKey,
+    /// The code points this font may be used for, if restricted by `unicode-range`.
+    pub unicode_range: Option<UnicodeRanges>,
 }
 
 impl Font {
@@ -182,6 +184,7 @@ impl Font {
         descriptor: FontDescriptor,
         actual_pt_size: Au,
         font_key: FontInstanceKey,
+        unicode_range: Option<UnicodeRanges>,
     ) -> Font {
         let metrics = handle.metrics();
 
@@ -194,6 +197,7 @@ impl Font {
             shape_cache: RefCell::new(HashMap::new()),
             glyph_advance_cache: RefCell::new(HashMap::new()),
             font_key,
+            unicode_range,
         }
     }
 
@@ -358,6 +362,13 @@ impl Font {
     }
 
     pub fn has_glyph_for(&self, codepoint: char) -> bool {
+        // Per CSS-FONTS § 4.5, a font face must not be used for code points outside of its
+        // `unicode-range`, even if it has a glyph for them.
+        if let Some(ref unicode_range) = self.unicode_range {
+            if !unicode_range.contains(codepoint) {
+                return false;
+            }
+        }
         self.glyph_index(codepoint).is_some()
     }
 
@@ -458,7 +469,7 @@ impl FontGroup {
     {
         self.families
             .iter_mut()
-            .filter_map(|family| family.font(&mut font_context))
+            .flat_map(|family| family.fonts(&mut font_context))
             .find(predicate)
     }
 
@@ -493,7 +504,7 @@ struct FontGroupFamily {
     font_descriptor: FontDescriptor,
     family_descriptor: FontFamilyDescriptor,
     loaded: bool,
-    font: Option<FontRef>,
+    fonts: Vec<FontRef>,
 }
 
 impl FontGroupFamily {
@@ -505,20 +516,20 @@ impl FontGroupFamily {
             font_descriptor,
             family_descriptor,
             loaded: false,
-            font: None,
+            fonts: vec![],
         }
     }
 
-    /// Returns the font within this family which matches the style. We'll fetch the data from the
-    /// `FontContext` the first time this method is called, and return a cached reference on
-    /// subsequent calls.
-    fn font<S: FontSource>(&mut self, font_context: &mut FontContext<S>) -> Option<FontRef> {
+    /// Returns the fonts within this family which match the style: usually one, or one per face
+    /// of a web font family split by `unicode-range`. We'll fetch the data from the `FontContext`
+    /// the first time this method is called, and return cached references on subsequent calls.
+    fn fonts<S: FontSource>(&mut self, font_context: &mut FontContext<S>) -> Vec<FontRef> {
         if !self.loaded {
-            self.font = font_context.font(&self.font_descriptor, &self.family_descriptor);
+            self.fonts = font_context.fonts(&self.font_descriptor, &self.family_descriptor);
             self.loaded = true;
         }
 
-        self.font.clone()
+        self.fonts.clone()
     }
 }
 
@@ -23,7 +23,7 @@ use webrender_api::{FontInstanceKey, FontKey};
 
 use crate::font::{FontFamilyDescriptor, FontFamilyName, FontSearchScope};
 use crate::font_context::FontSource;
-use crate::font_template::{FontTemplate, FontTemplateDescriptor};
+use crate::font_template::{FontTemplate, FontTemplateDescriptor, UnicodeRanges};
 use crate::platform::font_context::FontContextHandle;
 use crate::platform::font_list::{
     for_each_available_family, for_each_variation, system_default_family, SANS_SERIF_FONT_FAMILY,
@@ -33,18 +33,24 @@ use crate::platform::font_template::FontTemplateData;
 /// A list of font templates that make up a given font family.
 pub struct FontTemplates {
     templates: Vec<FontTemplate>,
+    /// The template identifier and `unicode-range` of each `@font-face` rule that added a
+    /// template to this family. Several rules may share a template. Only web font families
+    /// record these.
+    faces: Vec<(Atom, Option<UnicodeRanges>)>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FontTemplateInfo {
     pub font_template: Arc<FontTemplateData>,
     pub font_key: FontKey,
+    pub unicode_range: Option<UnicodeRanges>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SerializedFontTemplateInfo {
     pub serialized_font_template: SerializedFontTemplate,
     pub font_key: FontKey,
+    pub unicode_range: Option<UnicodeRanges>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -62,7 +68,31 @@ impl SerializedFontTemplate {
 
 impl FontTemplates {
     pub fn new() -> FontTemplates {
-        FontTemplates { templates: vec![] }
+        FontTemplates {
+            templates: vec![],
+            faces: vec![],
+        }
+    }
+
+    /// Find the fonts in this family that match a given descriptor. A web font family may be
+    /// split by `unicode-range` into several faces that all match the same descriptor, so every
+    /// exact match is returned, in the order the templates were added. Without an exact match,
+    /// this is the single font `find_font_for_style` picks.
+    pub fn find_fonts_for_style(
+        &mut self,
+        desc: &FontTemplateDescriptor,
+        fctx: &FontContextHandle,
+    ) -> Vec<Arc<FontTemplateData>> {
+        let exact_matches: Vec<_> = self
+            .templates
+            .iter_mut()
+            .filter_map(|template| template.data_for_descriptor(fctx, desc))
+            .collect();
+        if !exact_matches.is_empty() {
+            return exact_matches;
+        }
+
+        self.find_font_for_style(desc, fctx).into_iter().collect()
     }
 
     /// Find a font in this family that matches a given descriptor.
@@ -122,6 +152,34 @@ impl FontTemplates {
             self.templates.push(template);
         }
     }
+
+    /// Records that an `@font-face` rule with the given `unicode-range` uses the template with
+    /// the given identifier.
+    pub fn add_unicode_range(&mut self, identifier: Atom, unicode_range: Option<UnicodeRanges>) {
+        let face = (identifier, unicode_range);
+        if !self.faces.contains(&face) {
+            self.faces.push(face);
+        }
+    }
+
+    /// Returns the code points the template with the given identifier may be used for: the union
+    /// of the `unicode-range`s of the `@font-face` rules that use it. `None` means every code
+    /// point, either because one of those rules has no `unicode-range` or because none was
+    /// recorded.
+    pub fn unicode_range(&self, identifier: &Atom) -> Option<UnicodeRanges> {
+        let mut ranges = vec![];
+        for (face_identifier, unicode_range) in &self.faces {
+            if face_identifier == identifier {
+                ranges.extend(unicode_range.as_ref()?.iter().cloned());
+            }
+        }
+
+        if ranges.is_empty() {
+            None
+        } else {
+            Some(ranges.into_iter().collect())
+        }
+    }
 }
 
 /// Commands that the FontContext sends to the font cache thread.
@@ -133,8 +191,19 @@ pub enum Command {
         IpcSender<Reply>,
     ),
     GetFontInstance(FontKey, Au, IpcSender<FontInstanceKey>),
-    AddWebFont(LowercaseString, EffectiveSources, IpcSender<()>),
-    AddDownloadedWebFont(LowercaseString, ServoUrl, Vec<u8>, IpcSender<()>),
+    AddWebFont(
+        LowercaseString,
+        EffectiveSources,
+        Option<UnicodeRanges>,
+        IpcSender<()>,
+    ),
+    AddDownloadedWebFont(
+        LowercaseString,
+        ServoUrl,
+        Vec<u8>,
+        Option<UnicodeRanges>,
+        IpcSender<()>,
+    ),
     Exit(IpcSender<()>),
     Ping,
 }
@@ -142,7 +211,7 @@ pub enum Command {
 /// Reply messages sent from the font cache thread to the FontContext caller.
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Reply {
-    GetFontTemplateReply(Option<SerializedFontTemplateInfo>),
+    GetFontTemplateReply(Vec<SerializedFontTemplateInfo>),
 }
 
 /// The font cache thread itself. It maintains a list of reference counted
@@ -194,29 +263,31 @@ impl FontCache {
 
             match msg {
                 Command::GetFontTemplate(template_descriptor, family_descriptor, result) => {
-                    let maybe_font_template =
-                        self.find_font_template(&template_descriptor, &family_descriptor);
-                    match maybe_font_template {
-                        None => {
-                            let _ = result.send(Reply::GetFontTemplateReply(None));
-                        },
-                        Some(font_template_info) => {
-                            let (bytes_sender, bytes_receiver) =
-                                ipc::bytes_channel().expect("failed to create IPC channel");
-                            let serialized_font_template = SerializedFontTemplate {
-                                identifier: font_template_info.font_template.identifier.clone(),
-                                bytes_receiver,
-                            };
+                    let font_templates =
+                        self.find_font_templates(&template_descriptor, &family_descriptor);
+                    let mut bytes_senders = vec![];
+                    let mut serialized_font_templates = vec![];
+                    for font_template_info in &font_templates {
+                        let (bytes_sender, bytes_receiver) =
+                            ipc::bytes_channel().expect("failed to create IPC channel");
+                        let serialized_font_template = SerializedFontTemplate {
+                            identifier: font_template_info.font_template.identifier.clone(),
+                            bytes_receiver,
+                        };
+                        bytes_senders.push(bytes_sender);
+                        serialized_font_templates.push(SerializedFontTemplateInfo {
+                            serialized_font_template,
+                            font_key: font_template_info.font_key,
+                            unicode_range: font_template_info.unicode_range.clone(),
+                        });
+                    }
 
-                            let _ = result.send(Reply::GetFontTemplateReply(Some(
-                                SerializedFontTemplateInfo {
-                                    serialized_font_template,
-                                    font_key: font_template_info.font_key,
-                                },
-                            )));
-                            let _ = bytes_sender.send(&*font_template_info.font_template.bytes());
-                        },
-                    };
+                    let _ = result.send(Reply::GetFontTemplateReply(serialized_font_templates));
+                    for (bytes_sender, font_template_info) in
+                        bytes_senders.iter().zip(&font_templates)
+                    {
+                        let _ = bytes_sender.send(&*font_template_info.font_template.bytes());
+                    }
                 },
                 Command::GetFontInstance(font_key, size, result) => {
                     let webrender_api = &self.webrender_api;
@@ -231,12 +302,14 @@ impl FontCache {
 
                     let _ = result.send(instance_key);
                 },
-                Command::AddWebFont(family_name, sources, result) => {
-                    self.handle_add_web_font(family_name, sources, result);
+                Command::AddWebFont(family_name, sources, unicode_range, result) => {
+                    self.handle_add_web_font(family_name, sources, unicode_range, result);
                 },
-                Command::AddDownloadedWebFont(family_name, url, bytes, result) => {
+                Command::AddDownloadedWebFont(family_name, url, bytes, unicode_range, result) => {
+                    let identifier = Atom::from(url.to_string());
                     let templates = &mut self.web_families.get_mut(&family_name).unwrap();
-                    templates.add_template(Atom::from(url.to_string()), Some(bytes));
+                    templates.add_unicode_range(identifier.clone(), unicode_range);
+                    templates.add_template(identifier, Some(bytes));
                     drop(result.send(()));
                 },
                 Command::Ping => (),
@@ -252,6 +325,7 @@ impl FontCache {
         &mut self,
         family_name: LowercaseString,
         mut sources: EffectiveSources,
+        unicode_range: Option<UnicodeRanges>,
         sender: IpcSender<()>,
     ) {
         let src = if let Some(src) = sources.next() {
@@ -307,6 +381,7 @@ impl FontCache {
                                 let msg = Command::AddWebFont(
                                     family_name.clone(),
                                     sources.clone(),
+                                    unicode_range.clone(),
                                     sender.clone(),
                                 );
                                 channel_to_self.send(msg).unwrap();
@@ -326,6 +401,7 @@ impl FontCache {
                                     let msg = Command::AddWebFont(
                                         family_name.clone(),
                                         sources.clone(),
+                                        unicode_range.clone(),
                                         sender.clone(),
                                     );
                                     channel_to_self.send(msg).unwrap();
@@ -336,6 +412,7 @@ impl FontCache {
                                 family_name.clone(),
                                 url.clone(),
                                 bytes,
+                                unicode_range.clone(),
                                 sender.clone(),
                             );
                             channel_to_self.send(command).unwrap();
@@ -345,18 +422,22 @@ impl FontCache {
             },
             Source::Local(ref font) => {
                 let font_face_name = LowercaseString::new(&font.name);
-                let templates = &mut self.web_families.get_mut(&family_name).unwrap();
-                let mut found = false;
+                let mut identifiers = vec![];
                 for_each_variation(&font_face_name, |path| {
-                    found = true;
-                    templates.add_template(Atom::from(&*path), None);
+                    identifiers.push(Atom::from(&*path));
                 });
-                if found {
-                    sender.send(()).unwrap();
-                } else {
-                    let msg = Command::AddWebFont(family_name, sources, sender);
+                if identifiers.is_empty() {
+                    let msg = Command::AddWebFont(family_name, sources, unicode_range, sender);
                     self.channel_to_self.send(msg).unwrap();
+                    return;
+                }
+
+                for identifier in identifiers {
+                    let templates = &mut self.web_families.get_mut(&family_name).unwrap();
+                    templates.add_unicode_range(identifier.clone(), unicode_range.clone());
+                    templates.add_template(identifier, None);
                 }
+                sender.send(()).unwrap();
             },
         }
     }
@@ -411,22 +492,33 @@ impl FontCache {
         }
     }
 
-    fn find_font_in_web_family(
+    fn find_fonts_in_web_family(
         &mut self,
         template_descriptor: &FontTemplateDescriptor,
         family_name: &FontFamilyName,
-    ) -> Option<Arc<FontTemplateData>> {
+    ) -> Vec<(Arc<FontTemplateData>, Option<UnicodeRanges>)> {
         let family_name = LowercaseString::from(family_name);
 
         if self.web_families.contains_key(&family_name) {
             let templates = self.web_families.get_mut(&family_name).unwrap();
-            templates.find_font_for_style(template_descriptor, &self.font_context)
+            templates
+                .find_fonts_for_style(template_descriptor, &self.font_context)
+                .into_iter()
+                .map(|template| {
+                    let unicode_range = templates.unicode_range(&template.identifier);
+                    (template, unicode_range)
+                })
+                .collect()
         } else {
-            None
+            vec![]
         }
     }
 
-    fn get_font_template_info(&mut self, template: Arc<FontTemplateData>) -> FontTemplateInfo {
+    fn get_font_template_info(
+        &mut self,
+        template: Arc<FontTemplateData>,
+        unicode_range: Option<UnicodeRanges>,
+    ) -> FontTemplateInfo {
         let webrender_api = &self.webrender_api;
         let webrender_fonts = &mut self.webrender_fonts;
 
@@ -442,28 +534,37 @@ impl FontCache {
             });
 
         FontTemplateInfo {
+            unicode_range,
             font_template: template,
             font_key: font_key,
         }
     }
 
-    fn find_font_template(
+    fn find_font_templates(
         &mut self,
         template_descriptor: &FontTemplateDescriptor,
         family_descriptor: &FontFamilyDescriptor,
-    ) -> Option<FontTemplateInfo> {
-        match family_descriptor.scope {
-            FontSearchScope::Any => self
-                .find_font_in_web_family(&template_descriptor, &family_descriptor.name)
-                .or_else(|| {
-                    self.find_font_in_local_family(&template_descriptor, &family_descriptor.name)
-                }),
-
-            FontSearchScope::Local => {
-                self.find_font_in_local_family(&template_descriptor, &family_descriptor.name)
+    ) -> Vec<FontTemplateInfo> {
+        let web_templates = match family_descriptor.scope {
+            FontSearchScope::Any => {
+                self.find_fonts_in_web_family(&template_descriptor, &family_descriptor.name)
             },
-        }
-        .map(|t| self.get_font_template_info(t))
+            FontSearchScope::Local => vec![],
+        };
+
+        let templates = if web_templates.is_empty() {
+            self.find_font_in_local_family(&template_descriptor, &family_descriptor.name)
+                .map(|t| (t, None))
+                .into_iter()
+                .collect()
+        } else {
+            web_templates
+        };
+
+        templates
+            .into_iter()
+            .map(|(t, unicode_range)| self.get_font_template_info(t, unicode_range))
+            .collect()
     }
 }
 
@@ -513,12 +614,14 @@ impl FontCacheThread {
         &self,
         family: FamilyName,
         sources: EffectiveSources,
+        unicode_range: Option<UnicodeRanges>,
         sender: IpcSender<()>,
     ) {
         self.chan
             .send(Command::AddWebFont(
                 LowercaseString::new(&family.name),
                 sources,
+                unicode_range,
                 sender,
             ))
             .unwrap();
@@ -554,11 +657,11 @@ impl FontSource for FontCacheThread {
         instance_key.unwrap()
     }
 
-    fn font_template(
+    fn font_templates(
         &mut self,
         template_descriptor: FontTemplateDescriptor,
         family_descriptor: FontFamilyDescriptor,
-    ) -> Option<FontTemplateInfo> {
+    ) -> Vec<FontTemplateInfo> {
         let (response_chan, response_port) = ipc::channel().expect("failed to create IPC channel");
         self.chan
             .send(Command::GetFontTemplate(
@@ -580,18 +683,19 @@ impl FontSource for FontCacheThread {
         }
 
         match reply.unwrap() {
-            Reply::GetFontTemplateReply(maybe_serialized_font_template_info) => {
-                match maybe_serialized_font_template_info {
-                    None => None,
-                    Some(serialized_font_template_info) => Some(FontTemplateInfo {
+            Reply::GetFontTemplateReply(serialized_font_template_infos) => {
+                serialized_font_template_infos
+                    .into_iter()
+                    .map(|serialized_font_template_info| FontTemplateInfo {
                         font_template: Arc::new(
                             serialized_font_template_info
                                 .serialized_font_template
                                 .to_font_template_data(),
                         ),
                         font_key: serialized_font_template_info.font_key,
-                    }),
-                }
+                        unicode_range: serialized_font_template_info.unicode_range,
+                    })
+                    .collect()
             },
         }
     }
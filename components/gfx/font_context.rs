@@ -35,11 +35,11 @@ static FONT_CACHE_EPOCH: AtomicUsize = AtomicUsize::new(0);
 pub trait FontSource {
     fn get_font_instance(&mut self, key: FontKey, size: Au) -> FontInstanceKey;
 
-    fn font_template(
+    fn font_templates(
         &mut self,
         template_descriptor: FontTemplateDescriptor,
         family_descriptor: FontFamilyDescriptor,
-    ) -> Option<FontTemplateInfo>;
+    ) -> Vec<FontTemplateInfo>;
 }
 
 /// The FontContext represents the per-thread/thread state necessary for
@@ -54,8 +54,8 @@ pub struct FontContext<S: FontSource> {
     // TODO: The font context holds a strong ref to the cached fonts
     // so they will never be released. Find out a good time to drop them.
     // See bug https://github.com/servo/servo/issues/3300
-    font_cache: HashMap<FontCacheKey, Option<FontRef>>,
-    font_template_cache: HashMap<FontTemplateCacheKey, Option<FontTemplateInfo>>,
+    font_cache: HashMap<FontCacheKey, Vec<FontRef>>,
+    font_template_cache: HashMap<FontTemplateCacheKey, Vec<FontTemplateInfo>>,
 
     font_group_cache:
         HashMap<FontGroupCacheKey, Rc<RefCell<FontGroup>>, BuildHasherDefault<FnvHasher>>,
@@ -115,6 +115,19 @@ impl<S: FontSource> FontContext<S> {
         font_descriptor: &FontDescriptor,
         family_descriptor: &FontFamilyDescriptor,
     ) -> Option<FontRef> {
+        self.fonts(font_descriptor, family_descriptor)
+            .into_iter()
+            .next()
+    }
+
+    /// Returns all fonts matching the parameters. A web font family may be split by
+    /// `unicode-range` into several faces, each of which is a separate font. Fonts are cached, so
+    /// repeated calls will return references to the same underlying `Font`s.
+    pub fn fonts(
+        &mut self,
+        font_descriptor: &FontDescriptor,
+        family_descriptor: &FontFamilyDescriptor,
+    ) -> Vec<FontRef> {
         let cache_key = FontCacheKey {
             font_descriptor: font_descriptor.clone(),
             family_descriptor: family_descriptor.clone(),
@@ -125,28 +138,30 @@ impl<S: FontSource> FontContext<S> {
             .map(|v| v.clone())
             .unwrap_or_else(|| {
                 debug!(
-                    "FontContext::font cache miss for font_descriptor={:?} family_descriptor={:?}",
+                    "FontContext::fonts cache miss for font_descriptor={:?} family_descriptor={:?}",
                     font_descriptor, family_descriptor
                 );
 
-                let font = self
-                    .font_template(&font_descriptor.template_descriptor, family_descriptor)
-                    .and_then(|template_info| {
+                let fonts: Vec<_> = self
+                    .font_templates(&font_descriptor.template_descriptor, family_descriptor)
+                    .into_iter()
+                    .filter_map(|template_info| {
                         self.create_font(template_info, font_descriptor.to_owned())
                             .ok()
                     })
-                    .map(|font| Rc::new(RefCell::new(font)));
+                    .map(|font| Rc::new(RefCell::new(font)))
+                    .collect();
 
-                self.font_cache.insert(cache_key, font.clone());
-                font
+                self.font_cache.insert(cache_key, fonts.clone());
+                fonts
             })
     }
 
-    fn font_template(
+    fn font_templates(
         &mut self,
         template_descriptor: &FontTemplateDescriptor,
         family_descriptor: &FontFamilyDescriptor,
-    ) -> Option<FontTemplateInfo> {
+    ) -> Vec<FontTemplateInfo> {
         let cache_key = FontTemplateCacheKey {
             template_descriptor: template_descriptor.clone(),
             family_descriptor: family_descriptor.clone(),
@@ -154,18 +169,18 @@ impl<S: FontSource> FontContext<S> {
 
         self.font_template_cache.get(&cache_key).map(|v| v.clone()).unwrap_or_else(|| {
             debug!(
-                "FontContext::font_template cache miss for template_descriptor={:?} family_descriptor={:?}",
+                "FontContext::font_templates cache miss for template_descriptor={:?} family_descriptor={:?}",
                 template_descriptor,
                 family_descriptor
             );
 
-            let template_info = self.font_source.font_template(
+            let template_infos = self.font_source.font_templates(
                 template_descriptor.clone(),
                 family_descriptor.clone(),
             );
 
-            self.font_template_cache.insert(cache_key, template_info.clone());
-            template_info
+            self.font_template_cache.insert(cache_key, template_infos.clone());
+            template_infos
         })
     }
 
@@ -198,6 +213,7 @@ impl<S: FontSource> FontContext<S> {
             descriptor,
             actual_pt_size,
             font_instance_key,
+            info.unicode_range,
         ))
     }
 }
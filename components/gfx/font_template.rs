@@ -4,6 +4,9 @@
 
 use std::fmt::{Debug, Error, Formatter};
 use std::io::Error as IoError;
+use std::iter::FromIterator;
+use std::ops::RangeInclusive;
+use std::slice;
 use std::sync::{Arc, Weak};
 
 use serde::{Deserialize, Serialize};
@@ -81,6 +84,31 @@ impl<'a> From<&'a FontStyleStruct> for FontTemplateDescriptor {
     }
 }
 
+/// The ranges of code points outside of which a web font should not be used, as given by the
+/// `unicode-range` descriptor of its `@font-face` rule.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UnicodeRanges(Vec<RangeInclusive<u32>>);
+
+impl UnicodeRanges {
+    /// Returns true if the given code point falls within any of these ranges.
+    pub fn contains(&self, codepoint: char) -> bool {
+        self.0
+            .iter()
+            .any(|range| range.contains(&(codepoint as u32)))
+    }
+
+    /// Returns an iterator over these ranges.
+    pub fn iter(&self) -> slice::Iter<RangeInclusive<u32>> {
+        self.0.iter()
+    }
+}
+
+impl FromIterator<RangeInclusive<u32>> for UnicodeRanges {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u32>>>(iter: I) -> Self {
+        UnicodeRanges(iter.into_iter().collect())
+    }
+}
+
 /// This describes all the information needed to create
 /// font instance handles. It contains a unique
 /// FontTemplateData structure that is platform specific.
@@ -12,10 +12,11 @@ use std::rc::Rc;
 use app_units::Au;
 use gfx::font::{
     fallback_font_families, FontDescriptor, FontFamilyDescriptor, FontFamilyName, FontSearchScope,
+    ShapingFlags, ShapingOptions,
 };
 use gfx::font_cache_thread::{FontTemplateInfo, FontTemplates};
 use gfx::font_context::{FontContext, FontContextHandle, FontSource};
-use gfx::font_template::FontTemplateDescriptor;
+use gfx::font_template::{FontTemplateDescriptor, UnicodeRanges};
 use servo_arc::Arc;
 use servo_atoms::Atom;
 use style::properties::longhands::font_variant_caps::computed_value::T as FontVariantCaps;
@@ -24,6 +25,7 @@ use style::values::computed::font::{
     FamilyName, FontFamily, FontFamilyList, FontFamilyNameSyntax, FontSize, FontStretch, FontStyle,
     FontWeight, SingleFontFamily,
 };
+use unicode_script::Script;
 use webrender_api::{FontInstanceKey, FontKey, IdNamespace};
 
 struct TestFontSource {
@@ -40,12 +42,66 @@ impl TestFontSource {
         let mut csstest_basic = FontTemplates::new();
         Self::add_face(&mut csstest_basic, "csstest-basic-regular", None);
 
+        let mut csstest_ascii_restricted = FontTemplates::new();
+        Self::add_face(
+            &mut csstest_ascii_restricted,
+            "csstest-ascii",
+            Some("csstest-ascii-restricted"),
+        );
+        csstest_ascii_restricted.add_unicode_range(
+            Atom::from("csstest-ascii-restricted"),
+            Some(unicode_range('b', 'z')),
+        );
+
+        // One family split into two faces by unicode-range.
+        let mut csstest_ascii_split = FontTemplates::new();
+        Self::add_face(
+            &mut csstest_ascii_split,
+            "csstest-ascii",
+            Some("csstest-ascii-a-m"),
+        );
+        csstest_ascii_split.add_unicode_range(
+            Atom::from("csstest-ascii-a-m"),
+            Some(unicode_range('a', 'm')),
+        );
+        Self::add_face(
+            &mut csstest_ascii_split,
+            "csstest-ascii",
+            Some("csstest-ascii-n-z"),
+        );
+        csstest_ascii_split.add_unicode_range(
+            Atom::from("csstest-ascii-n-z"),
+            Some(unicode_range('n', 'z')),
+        );
+
+        // Two faces with different unicode-ranges sharing the same font data.
+        let mut csstest_ascii_shared = FontTemplates::new();
+        Self::add_face(
+            &mut csstest_ascii_shared,
+            "csstest-ascii",
+            Some("csstest-ascii-shared"),
+        );
+        csstest_ascii_shared.add_unicode_range(
+            Atom::from("csstest-ascii-shared"),
+            Some(unicode_range('a', 'c')),
+        );
+        csstest_ascii_shared.add_unicode_range(
+            Atom::from("csstest-ascii-shared"),
+            Some(unicode_range('x', 'z')),
+        );
+
         let mut fallback = FontTemplates::new();
         Self::add_face(&mut fallback, "csstest-basic-regular", Some("fallback"));
 
         let mut families = HashMap::new();
         families.insert("CSSTest ASCII".to_owned(), csstest_ascii);
         families.insert("CSSTest Basic".to_owned(), csstest_basic);
+        families.insert(
+            "CSSTest ASCII Restricted".to_owned(),
+            csstest_ascii_restricted,
+        );
+        families.insert("CSSTest ASCII Split".to_owned(), csstest_ascii_split);
+        families.insert("CSSTest ASCII Shared".to_owned(), csstest_ascii_shared);
         families.insert(fallback_font_families(None)[0].to_owned(), fallback);
 
         TestFontSource {
@@ -73,24 +129,34 @@ impl FontSource for TestFontSource {
         FontInstanceKey(IdNamespace(0), 0)
     }
 
-    fn font_template(
+    fn font_templates(
         &mut self,
         template_descriptor: FontTemplateDescriptor,
         family_descriptor: FontFamilyDescriptor,
-    ) -> Option<FontTemplateInfo> {
+    ) -> Vec<FontTemplateInfo> {
         let handle = &self.handle;
 
         self.find_font_count.set(self.find_font_count.get() + 1);
-        self.families
-            .get_mut(family_descriptor.name())
-            .and_then(|family| family.find_font_for_style(&template_descriptor, handle))
+        let family = match self.families.get_mut(family_descriptor.name()) {
+            Some(family) => family,
+            None => return vec![],
+        };
+        family
+            .find_fonts_for_style(&template_descriptor, handle)
+            .into_iter()
             .map(|template| FontTemplateInfo {
+                unicode_range: family.unicode_range(&template.identifier),
                 font_template: template,
                 font_key: FontKey(IdNamespace(0), 0),
             })
+            .collect()
     }
 }
 
+fn unicode_range(first: char, last: char) -> UnicodeRanges {
+    vec![first as u32..=last as u32].into_iter().collect()
+}
+
 fn style() -> FontStyleStruct {
     let mut style = FontStyleStruct {
         font_family: FontFamily::serif(),
@@ -220,6 +286,104 @@ fn test_font_fallback() {
     );
 }
 
+#[test]
+fn test_font_fallback_outside_unicode_range() {
+    let source = TestFontSource::new();
+    let mut context = FontContext::new(source);
+
+    let mut style = style();
+    style.set_font_family(font_family(vec![
+        "CSSTest ASCII Restricted",
+        "CSSTest ASCII",
+    ]));
+
+    let group = context.font_group(Arc::new(style));
+
+    let font = group
+        .borrow_mut()
+        .find_by_codepoint(&mut context, 'b')
+        .unwrap();
+    assert_eq!(
+        &*font.borrow().identifier(),
+        "csstest-ascii-restricted",
+        "a font should be used for code points within its unicode-range"
+    );
+
+    let font = group
+        .borrow_mut()
+        .find_by_codepoint(&mut context, 'a')
+        .unwrap();
+    assert_eq!(
+        &*font.borrow().identifier(),
+        "csstest-ascii",
+        "a font should not be used for code points outside of its unicode-range"
+    );
+}
+
+#[test]
+fn test_font_family_split_by_unicode_range() {
+    let source = TestFontSource::new();
+    let mut context = FontContext::new(source);
+
+    let mut style = style();
+    style.set_font_family(font_family(vec!["CSSTest ASCII Split"]));
+
+    let group = context.font_group(Arc::new(style));
+
+    let font = group
+        .borrow_mut()
+        .find_by_codepoint(&mut context, 'b')
+        .unwrap();
+    assert_eq!(
+        &*font.borrow().identifier(),
+        "csstest-ascii-a-m",
+        "the face whose unicode-range contains the code point should be used"
+    );
+
+    let font = group
+        .borrow_mut()
+        .find_by_codepoint(&mut context, 'x')
+        .unwrap();
+    assert_eq!(
+        &*font.borrow().identifier(),
+        "csstest-ascii-n-z",
+        "every face of the family should be tried before falling back"
+    );
+}
+
+#[test]
+fn test_font_faces_sharing_data_keep_their_unicode_ranges() {
+    let source = TestFontSource::new();
+    let mut context = FontContext::new(source);
+
+    let mut style = style();
+    style.set_font_family(font_family(vec!["CSSTest ASCII Shared", "CSSTest ASCII"]));
+
+    let group = context.font_group(Arc::new(style));
+
+    for &codepoint in &['b', 'y'] {
+        let font = group
+            .borrow_mut()
+            .find_by_codepoint(&mut context, codepoint)
+            .unwrap();
+        assert_eq!(
+            &*font.borrow().identifier(),
+            "csstest-ascii-shared",
+            "the unicode-range of every face using the font data should be honored"
+        );
+    }
+
+    let font = group
+        .borrow_mut()
+        .find_by_codepoint(&mut context, 'm')
+        .unwrap();
+    assert_eq!(
+        &*font.borrow().identifier(),
+        "csstest-ascii",
+        "the font data should not be used outside of the unicode-ranges of its faces"
+    );
+}
+
 #[test]
 fn test_font_template_is_cached() {
     let source = TestFontSource::new();
@@ -256,3 +420,44 @@ fn test_font_template_is_cached() {
         "we should only have fetched the template data from the cache thread once"
     );
 }
+
+#[test]
+fn test_shaped_text_is_cached() {
+    let source = TestFontSource::new();
+    let mut context = FontContext::new(source);
+
+    let font_descriptor = FontDescriptor {
+        template_descriptor: FontTemplateDescriptor {
+            weight: FontWeight::normal(),
+            stretch: FontStretch::hundred(),
+            style: FontStyle::normal(),
+        },
+        variant: FontVariantCaps::Normal,
+        pt_size: Au(10),
+    };
+    let family_descriptor =
+        FontFamilyDescriptor::new(FontFamilyName::from("CSSTest Basic"), FontSearchScope::Any);
+    let options = ShapingOptions {
+        letter_spacing: None,
+        word_spacing: Au(0),
+        script: Script::Common,
+        flags: ShapingFlags::empty(),
+    };
+
+    // Identical list item markers, e.g. every "1." of a set of nested lists, are shaped once.
+    let glyphs1 = context
+        .font(&font_descriptor, &family_descriptor)
+        .unwrap()
+        .borrow_mut()
+        .shape_text("1.\u{a0}", &options);
+    let glyphs2 = context
+        .font(&font_descriptor, &family_descriptor)
+        .unwrap()
+        .borrow_mut()
+        .shape_text("1.\u{a0}", &options);
+
+    assert!(
+        std::sync::Arc::ptr_eq(&glyphs1, &glyphs2),
+        "the same glyphs should have been returned for identical text"
+    );
+}
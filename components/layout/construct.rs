@@ -60,7 +60,7 @@ use crate::fragment::{
 };
 use crate::inline::{InlineFlow, InlineFragmentNodeFlags, InlineFragmentNodeInfo};
 use crate::linked_list::prepend_from;
-use crate::list_item::{ListItemFlow, ListStyleTypeContent};
+use crate::list_item::{self, ListItemFlow};
 use crate::multicol::{MulticolColumnFlow, MulticolFlow};
 use crate::table::TableFlow;
 use crate::table_caption::TableCaptionFlow;
@@ -1514,18 +1514,26 @@ where
         flotation: Float,
     ) -> ConstructionResult {
         let flotation = FloatKind::from_property(flotation);
-        let marker_fragments = match node.style(self.style_context()).get_list().list_style_image {
+        // TODO: Build markers from `::marker { content: ... }` (e.g. `"(" counter(list-item)
+        // ")"`) once the style system supports the `::marker` pseudo-element. Each content item
+        // would become a marker fragment here, the same way `::before` content is built.
+        let style = node.style(self.style_context());
+        let layout_context = self.layout_context;
+        let marker_fragments = match style.get_list().list_style_image {
             Image::Url(ref url_value) => {
+                // TODO: Image markers are always drawn at their intrinsic size. Honoring
+                // `object-fit` for an explicitly sized marker box needs `::marker` and Servo
+                // support for `object-fit`, which is currently Gecko-only.
                 let image_info = Box::new(ImageFragmentInfo::new(
                     url_value.url().cloned(),
                     None,
                     node,
-                    &self.layout_context,
+                    layout_context,
                 ));
                 vec![Fragment::new(
                     node,
                     SpecificFragmentInfo::Image(image_info),
-                    self.layout_context,
+                    layout_context,
                 )]
             },
             // XXX: Non-None image types unimplemented.
@@ -1534,32 +1542,10 @@ where
             Image::Gradient(..) |
             Image::PaintWorklet(..) |
             Image::CrossFade(..) |
-            Image::None => match ListStyleTypeContent::from_list_style_type(
-                node.style(self.style_context()).get_list().list_style_type,
-            ) {
-                ListStyleTypeContent::None => Vec::new(),
-                ListStyleTypeContent::StaticText(ch) => {
-                    let text = format!("{}\u{a0}", ch);
-                    let mut unscanned_marker_fragments = LinkedList::new();
-                    unscanned_marker_fragments.push_back(Fragment::new(
-                        node,
-                        SpecificFragmentInfo::UnscannedText(Box::new(
-                            UnscannedTextFragmentInfo::new(Box::<str>::from(text), None),
-                        )),
-                        self.layout_context,
-                    ));
-                    let marker_fragments =
-                        with_thread_local_font_context(self.layout_context, |mut font_context| {
-                            TextRunScanner::new()
-                                .scan_for_runs(&mut font_context, unscanned_marker_fragments)
-                        });
-                    marker_fragments.fragments
-                },
-                ListStyleTypeContent::GeneratedContent(info) => vec![Fragment::new(
-                    node,
-                    SpecificFragmentInfo::GeneratedContent(info),
-                    self.layout_context,
-                )],
+            Image::None => {
+                list_item::build_marker_fragments(layout_context, &*style, |specific| {
+                    Fragment::new(node, specific, layout_context)
+                })
             },
         };
 
@@ -1581,6 +1567,11 @@ where
                 flotation,
             )),
             ListStylePosition::Inside => {
+                // Inside markers are laid out as ordinary inline fragments of the first line, so
+                // they sit on its baseline next to tall inline content just like text does.
+                //
+                // FIXME: Markers carry the list item's own style, so a `vertical-align` set on
+                // the list item also applies to them. A `::marker` style would reset it.
                 for marker_fragment in marker_fragments {
                     initial_fragments.fragments.push_back(marker_fragment)
                 }
@@ -1977,6 +1968,10 @@ where
             //
             // TODO(pcwalton): Make this only trigger for blocks and handle the other `display`
             // properties separately.
+            //
+            // This includes `display: contents`, which still gets a block flow here. That flow
+            // carries the element's `counter-reset` and `counter-increment`, so markers of
+            // descendant list items are still numbered within the counter scope it creates.
             (_, float_value, _) => {
                 let float_kind = FloatKind::from_property(float_value);
                 // List items contribute their own special flows.
@@ -7,6 +7,7 @@
 use std::cell::{RefCell, RefMut};
 use std::collections::HashMap;
 use std::hash::BuildHasherDefault;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
@@ -19,6 +20,7 @@ use net_traits::image_cache::{
     ImageCache, ImageCacheResult, ImageOrMetadataAvailable, UsePlaceholder,
 };
 use parking_lot::RwLock;
+use profile_traits::time::{ProfilerChan, ProfilerMsg};
 use script_layout_interface::{PendingImage, PendingImageState};
 use script_traits::Painter;
 use servo_atoms::Atom;
@@ -84,6 +86,9 @@ pub struct LayoutContext<'a> {
 
     /// A list of in-progress image loads to be shared with the script thread.
     pub pending_images: Mutex<Vec<PendingImage>>,
+
+    /// Counters accumulated during this layout, for profiling.
+    pub statistics: LayoutStatistics,
 }
 
 impl<'a> Drop for LayoutContext<'a> {
@@ -174,6 +179,43 @@ impl<'a> LayoutContext<'a> {
     }
 }
 
+/// Counters accumulated over the course of a single layout, reported to the time profiler once
+/// layout is done.
+#[derive(Debug, Default)]
+pub struct LayoutStatistics {
+    /// The number of list item markers that have been laid out.
+    pub markers_laid_out: AtomicUsize,
+    /// The number of times the font context has been accessed to lay out list item markers.
+    pub marker_font_context_invocations: AtomicUsize,
+}
+
+impl LayoutStatistics {
+    #[inline]
+    pub fn record_marker_laid_out(&self) {
+        self.markers_laid_out.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn record_marker_font_context_invocation(&self) {
+        self.marker_font_context_invocations
+            .fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Sends these statistics to the time profiler, unless this layout had no list item markers.
+    pub fn report(&self, time_profiler_chan: &ProfilerChan) {
+        let markers_laid_out = self.markers_laid_out.load(Ordering::Relaxed);
+        let marker_font_context_invocations =
+            self.marker_font_context_invocations.load(Ordering::Relaxed);
+        if markers_laid_out == 0 && marker_font_context_invocations == 0 {
+            return;
+        }
+        time_profiler_chan.send(ProfilerMsg::ListMarkers(
+            markers_laid_out,
+            marker_font_context_invocations,
+        ))
+    }
+}
+
 /// A registered painter
 pub trait RegisteredPainter: RegisteredSpeculativePainter + Painter {}
 
@@ -2174,6 +2174,7 @@ impl Fragment {
 
         // TODO(#17715): emit text-emphasis marks here.
         // (just push another TextDisplayItem?)
+        // List item markers share the list item's style but must not get emphasis marks.
 
         // Line-Through
         if text_decorations.line_through {
@@ -61,6 +61,7 @@ use crate::flow_list::{FlowList, FlowListIterator, MutFlowListIterator};
 use crate::flow_ref::{FlowRef, WeakFlowRef};
 use crate::fragment::{CoordinateSystem, Fragment, FragmentBorderBoxIterator, Overflow};
 use crate::inline::InlineFlow;
+use crate::list_item::ListItemFlow;
 use crate::model::{CollapsibleMargins, IntrinsicISizes};
 use crate::parallel::FlowParallelInfo;
 use crate::table::TableFlow;
@@ -141,6 +142,17 @@ pub trait Flow: HasBaseFlow + fmt::Debug + Sync + Send + 'static {
         panic!("called as_mut_inline() on a non-inline flow")
     }
 
+    /// If this is a list item flow, returns the underlying object. Returns `None` otherwise.
+    fn as_list_item(&self) -> Option<&ListItemFlow> {
+        None
+    }
+
+    /// If this is a list item flow, returns the underlying object, borrowed mutably. Returns
+    /// `None` otherwise.
+    fn as_mut_list_item(&mut self) -> Option<&mut ListItemFlow> {
+        None
+    }
+
     /// If this is a table wrapper flow, returns the underlying object. Fails otherwise.
     fn as_table_wrapper(&self) -> &TableWrapperFlow {
         panic!("called as_table_wrapper() on a non-tablewrapper flow")
@@ -427,6 +439,15 @@ pub trait Flow: HasBaseFlow + fmt::Debug + Sync + Send + 'static {
     /// Mutably iterates through fragments in this flow.
     fn mutate_fragments(&mut self, mutator: &mut dyn FnMut(&mut Fragment));
 
+    /// Iterates through all fragments in this flow, including any list item markers. The default
+    /// implementation visits the fragment of the underlying block flow, if there is one.
+    fn iterate_fragments(&self, iterator: &mut dyn FnMut(&Fragment)) {
+        match self.class() {
+            FlowClass::Inline | FlowClass::TableColGroup => {},
+            _ => (*iterator)(&self.as_block().fragment),
+        }
+    }
+
     /// Marks this flow as the root flow. The default implementation is a no-op.
     fn mark_as_root(&mut self) {
         debug!("called mark_as_root() on a flow of type {:?}", self.class());
@@ -549,6 +570,10 @@ pub trait ImmutableFlowUtils {
     fn floats_might_flow_through(self) -> bool;
 
     fn baseline_offset_of_last_line_box_in_flow(self) -> Option<Au>;
+
+    /// Returns the baseline of the first line box in this flow, descending into in-flow block
+    /// children (e.g. the `<p>` in `<li><p>text</p></li>`) until one is found.
+    fn baseline_offset_of_first_line_box_in_flow(self) -> Option<Au>;
 }
 
 pub trait MutableFlowUtils {
@@ -1070,8 +1095,11 @@ impl BaseFlow {
                     },
                 }
 
+                // List items implicitly increment `list-item`, whatever their counter properties.
                 if !style.get_counters().counter_reset.is_empty() ||
-                    !style.get_counters().counter_increment.is_empty()
+                    !style.get_counters().counter_increment.is_empty() ||
+                    !style.get_counters().counter_set.is_empty() ||
+                    style.get_box().display.is_list_item()
                 {
                     flags.insert(FlowFlags::AFFECTS_COUNTERS)
                 }
@@ -1335,6 +1363,28 @@ impl<'a> ImmutableFlowUtils for &'a dyn Flow {
         }
         None
     }
+
+    fn baseline_offset_of_first_line_box_in_flow(self) -> Option<Au> {
+        for kid in self.base().children.iter() {
+            if kid.is_inline_flow() {
+                if let Some(baseline_offset) = kid.as_inline().baseline_offset_of_first_line() {
+                    return Some(kid.base().position.start.b + baseline_offset);
+                }
+            }
+            // Floats are out of flow, so their lines never hold the first line of this flow.
+            if kid.is_block_like() &&
+                !kid.base().flags.is_float() &&
+                !kid.base()
+                    .flags
+                    .contains(FlowFlags::IS_ABSOLUTELY_POSITIONED)
+            {
+                if let Some(baseline_offset) = kid.baseline_offset_of_first_line_box_in_flow() {
+                    return Some(kid.base().position.start.b + baseline_offset);
+                }
+            }
+        }
+        None
+    }
 }
 
 impl<'a> MutableFlowUtils for &'a mut dyn Flow {
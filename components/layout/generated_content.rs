@@ -9,20 +9,25 @@
 //! as possible.
 
 use std::collections::{HashMap, LinkedList};
+use std::ops::RangeInclusive;
 
+use app_units::Au;
 use lazy_static::lazy_static;
 use script_layout_interface::wrapper_traits::PseudoElementType;
+use servo_config::pref;
 use smallvec::SmallVec;
+use style::computed_values::direction::T as Direction;
+use style::computed_values::list_style_position::T as ListStylePosition;
 use style::computed_values::list_style_type::T as ListStyleType;
 use style::properties::ComputedValues;
 use style::selector_parser::RestyleDamage;
 use style::servo::restyle_damage::ServoRestyleDamage;
-use style::values::generics::counters::ContentItem;
+use style::values::generics::counters::{ContentItem, CounterPair};
 use style::values::specified::list::{QuotePair, Quotes};
 
 use crate::context::{with_thread_local_font_context, LayoutContext};
 use crate::display_list::items::OpaqueNode;
-use crate::flow::{Flow, FlowFlags, GetBaseFlow, ImmutableFlowUtils};
+use crate::flow::{Flow, FlowClass, FlowFlags, GetBaseFlow, ImmutableFlowUtils};
 use crate::fragment::{
     Fragment, GeneratedContentInfo, SpecificFragmentInfo, UnscannedTextFragmentInfo,
 };
@@ -47,7 +52,6 @@ lazy_static! {
 
 // Decimal styles per CSS-COUNTER-STYLES § 6.1:
 static DECIMAL: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
-// TODO(pcwalton): `decimal-leading-zero`
 static ARABIC_INDIC: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
 // TODO(pcwalton): `armenian`, `upper-armenian`, `lower-armenian`
 static BENGALI: [char; 10] = ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'];
@@ -66,7 +70,6 @@ static MONGOLIAN: [char; 10] = ['᠐', '᠑', '᠒', '᠓', '᠔', '᠕', '᠖',
 static MYANMAR: [char; 10] = ['၀', '၁', '၂', '၃', '၄', '၅', '၆', '၇', '၈', '၉'];
 static ORIYA: [char; 10] = ['୦', '୧', '୨', '୩', '୪', '୫', '୬', '୭', '୮', '୯'];
 static PERSIAN: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
-// TODO(pcwalton): `lower-roman`, `upper-roman`
 static TELUGU: [char; 10] = ['౦', '౧', '౨', '౩', '౪', '౫', '౬', '౭', '౮', '౯'];
 static THAI: [char; 10] = ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'];
 static TIBETAN: [char; 10] = ['༠', '༡', '༢', '༣', '༤', '༥', '༦', '༧', '༨', '༩'];
@@ -109,14 +112,44 @@ static KATAKANA_IROHA: [char; 47] = [
     'コ', 'エ', 'テ', 'ア', 'サ', 'キ', 'ユ', 'メ', 'ミ', 'シ', 'ヱ', 'ヒ', 'モ', 'セ', 'ス',
 ];
 
+// Additive styles per CSS-COUNTER-STYLES § 6.1:
+static LOWER_ROMAN: [(usize, &str); 13] = [
+    (1000, "m"),
+    (900, "cm"),
+    (500, "d"),
+    (400, "cd"),
+    (100, "c"),
+    (90, "xc"),
+    (50, "l"),
+    (40, "xl"),
+    (10, "x"),
+    (9, "ix"),
+    (5, "v"),
+    (4, "iv"),
+    (1, "i"),
+];
+static UPPER_ROMAN: [(usize, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
 /// The generated content resolution traversal.
 pub struct ResolveGeneratedContent<'a> {
     /// The layout context.
     layout_context: &'a LayoutContext<'a>,
-    /// The counter representing an ordered list item.
-    list_item: Counter,
-    /// Named CSS counters.
-    counters: HashMap<String, Counter>,
+    /// The CSS counters in scope.
+    counters: Counters,
     /// The level of quote nesting.
     quote: u32,
 }
@@ -126,8 +159,7 @@ impl<'a> ResolveGeneratedContent<'a> {
     pub fn new(layout_context: &'a LayoutContext) -> ResolveGeneratedContent<'a> {
         ResolveGeneratedContent {
             layout_context: layout_context,
-            list_item: Counter::new(),
-            counters: HashMap::new(),
+            counters: Counters::new(),
             quote: 0,
         }
     }
@@ -141,8 +173,28 @@ impl<'a> InorderFlowTraversal for ResolveGeneratedContent<'a> {
             level: level,
             is_block: flow.is_block_like(),
             incremented: false,
+            fragments_mutated: 0,
+            split_marker_fragments: Vec::new(),
         };
-        flow.mutate_fragments(&mut |fragment| mutator.mutate_fragment(fragment))
+        flow.mutate_fragments(&mut |fragment| mutator.mutate_fragment(fragment));
+
+        let split_marker_fragments = mutator.split_marker_fragments;
+        if split_marker_fragments.is_empty() {
+            return;
+        }
+        if let Some(list_item) = flow.as_mut_list_item() {
+            // Outside markers are the list item's last fragments, so their pieces can just follow.
+            list_item
+                .marker_fragments
+                .extend(split_marker_fragments.into_iter().map(|(_, marker)| marker))
+        } else if flow.class() == FlowClass::Inline {
+            // Inside markers are fragments of the first line's inline flow. Going backwards keeps
+            // the indices of earlier markers valid.
+            let fragments = &mut flow.as_mut_inline().fragments.fragments;
+            for (index, marker) in split_marker_fragments.into_iter().rev() {
+                fragments.insert(index + 1, marker)
+            }
+        }
     }
 
     #[inline]
@@ -166,10 +218,19 @@ struct ResolveGeneratedContentFragmentMutator<'a, 'b: 'a> {
     is_block: bool,
     /// Whether we've incremented the counter yet.
     incremented: bool,
+    /// The number of the flow's fragments seen so far.
+    fragments_mutated: usize,
+    /// The fragments after the first of a marker whose text was split into several, each with the
+    /// index of the marker fragment it follows, to be added to the flow once its fragments have
+    /// been mutated.
+    split_marker_fragments: Vec<(usize, Fragment)>,
 }
 
 impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
     fn mutate_fragment(&mut self, fragment: &mut Fragment) {
+        let index = self.fragments_mutated;
+        self.fragments_mutated += 1;
+
         // We only reset and/or increment counters once per flow. This avoids double-incrementing
         // counters on list items (once for the main fragment and once for the marker).
         if !self.incremented {
@@ -192,14 +253,54 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
 
             match **info {
                 GeneratedContentInfo::ListItem => {
-                    new_info = self.traversal.list_item.render(
-                        self.traversal.layout_context,
-                        fragment.node,
-                        fragment.pseudo.clone(),
-                        fragment.style.clone(),
-                        list_style_type,
-                        RenderingMode::Suffix(".\u{00a0}"),
-                    )
+                    // Outside markers are separated from the content by `marker_separation`.
+                    let direction = fragment.style().get_inherited_box().direction;
+                    let mode = match (
+                        fragment.style().get_list().list_style_position,
+                        pref!(layout.list_numbers.repeat_symbols),
+                    ) {
+                        (ListStylePosition::Outside, false) => RenderingMode::Marker(direction, ""),
+                        (ListStylePosition::Inside, false) => {
+                            RenderingMode::Marker(direction, "\u{00a0}")
+                        },
+                        (ListStylePosition::Outside, true) => RenderingMode::Repeat(
+                            direction,
+                            static_representation(ListStyleType::Disc),
+                            "",
+                        ),
+                        (ListStylePosition::Inside, true) => RenderingMode::Repeat(
+                            direction,
+                            static_representation(ListStyleType::Disc),
+                            "\u{00a0}",
+                        ),
+                    };
+                    let text = self
+                        .traversal
+                        .counters
+                        .list_item
+                        .text(list_style_type, mode);
+                    if !text.is_empty() {
+                        self.traversal
+                            .layout_context
+                            .statistics
+                            .record_marker_font_context_invocation();
+                        // Right-to-left markers are split at the bidi level change between the
+                        // number and its suffix, so they can take several fragments.
+                        let mut marker_fragments = render_text_fragments(
+                            self.traversal.layout_context,
+                            fragment.node,
+                            fragment.pseudo.clone(),
+                            fragment.style.clone(),
+                            text,
+                        )
+                        .into_iter();
+                        new_info = marker_fragments.next().map(|marker| marker.specific);
+                        for marker in marker_fragments {
+                            let marker =
+                                fragment.transform(marker.border_box.size, marker.specific);
+                            self.split_marker_fragments.push((index, marker))
+                        }
+                    }
                 },
                 GeneratedContentInfo::ContentItem(ContentItem::Counter(
                     ref counter_name,
@@ -209,7 +310,7 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
                     let counter = self
                         .traversal
                         .counters
-                        .get(&*counter_name.0)
+                        .counter(&*counter_name.0)
                         .unwrap_or(&temporary_counter);
                     new_info = counter.render(
                         self.traversal.layout_context,
@@ -229,7 +330,7 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
                     let counter = self
                         .traversal
                         .counters
-                        .get(&*counter_name.0)
+                        .counter(&*counter_name.0)
                         .unwrap_or(&temporary_counter);
                     new_info = counter.render(
                         self.traversal.layout_context,
@@ -291,55 +392,15 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
     }
 
     fn reset_and_increment_counters_as_necessary(&mut self, fragment: &mut Fragment) {
-        let mut list_style_type = fragment.style().get_list().list_style_type;
-        if !self.is_block || !fragment.style().get_box().display.is_list_item() {
-            list_style_type = ListStyleType::None
-        }
-
-        match list_style_type {
-            ListStyleType::Disc |
-            ListStyleType::None |
-            ListStyleType::Circle |
-            ListStyleType::Square |
-            ListStyleType::DisclosureOpen |
-            ListStyleType::DisclosureClosed => {},
-            _ => self.traversal.list_item.increment(self.level, 1),
-        }
-
-        // Truncate down counters.
-        for (_, counter) in &mut self.traversal.counters {
-            counter.truncate_to_level(self.level);
-        }
-        self.traversal.list_item.truncate_to_level(self.level);
-
-        for pair in &*fragment.style().get_counters().counter_reset {
-            let counter_name = &*pair.name.0;
-            if let Some(ref mut counter) = self.traversal.counters.get_mut(counter_name) {
-                counter.reset(self.level, pair.value);
-                continue;
-            }
-
-            let mut counter = Counter::new();
-            counter.reset(self.level, pair.value);
-            self.traversal
-                .counters
-                .insert(counter_name.to_owned(), counter);
-        }
-
-        for pair in &*fragment.style().get_counters().counter_increment {
-            let counter_name = &*pair.name.0;
-            if let Some(ref mut counter) = self.traversal.counters.get_mut(counter_name) {
-                counter.increment(self.level, pair.value);
-                continue;
-            }
-
-            let mut counter = Counter::new();
-            counter.increment(self.level, pair.value);
-            self.traversal
-                .counters
-                .insert(counter_name.to_owned(), counter);
-        }
-
+        let is_list_item = self.is_block && fragment.style().get_box().display.is_list_item();
+        let counters = fragment.style().get_counters();
+        self.traversal.counters.update(
+            self.level,
+            is_list_item,
+            &counters.counter_reset,
+            &counters.counter_increment,
+            &counters.counter_set,
+        );
         self.incremented = true
     }
 
@@ -364,7 +425,96 @@ impl<'a, 'b> ResolveGeneratedContentFragmentMutator<'a, 'b> {
     }
 }
 
+/// The CSS counters in scope at some point of the generated content resolution traversal.
+#[derive(Default)]
+pub struct Counters {
+    /// The counter representing an ordered list item.
+    list_item: Counter,
+    /// Named CSS counters.
+    named: HashMap<String, Counter>,
+}
+
+impl Counters {
+    /// Creates a set of counters with no instances in scope.
+    pub fn new() -> Counters {
+        Counters {
+            list_item: Counter::new(),
+            named: HashMap::new(),
+        }
+    }
+
+    /// Applies the counter properties of an element at the given level of the flow tree, after
+    /// dropping the counter instances of deeper levels, which are out of scope.
+    ///
+    /// List items implicitly increment `list-item` by one (CSS-LISTS § 4.6) whatever their
+    /// `list-style-type`, unless their `counter-increment` names `list-item`, in which case its
+    /// value replaces the implicit increment.
+    pub fn update(
+        &mut self,
+        level: u32,
+        is_list_item: bool,
+        counter_reset: &[CounterPair<i32>],
+        counter_increment: &[CounterPair<i32>],
+        counter_set: &[CounterPair<i32>],
+    ) {
+        for (_, counter) in &mut self.named {
+            counter.truncate_to_level(level);
+        }
+        self.list_item.truncate_to_level(level);
+
+        let increments_list_item = counter_increment
+            .iter()
+            .any(|pair| &*pair.name.0 == "list-item");
+        if is_list_item && !increments_list_item {
+            self.list_item.increment(level, 1)
+        }
+
+        for pair in counter_reset {
+            self.counter_mut(&*pair.name.0).reset(level, pair.value)
+        }
+
+        for pair in counter_increment {
+            self.counter_mut(&*pair.name.0).increment(level, pair.value)
+        }
+
+        // Per CSS-LISTS § 4.4, `counter-set` applies after `counter-reset` and
+        // `counter-increment`, so it wins over both.
+        for pair in counter_set {
+            self.counter_mut(&*pair.name.0).set(level, pair.value)
+        }
+    }
+
+    /// Returns the innermost value of the counter with the given name, or zero if there is no
+    /// instance of it in scope.
+    pub fn value(&self, name: &str) -> i32 {
+        self.counter(name)
+            .and_then(|counter| counter.values.last())
+            .map_or(0, |value| value.value)
+    }
+
+    /// Returns the counter with the given name. `list-item` is the counter that numbers list
+    /// item markers, so `counter(list-item)` in any `content` shows the marker's ordinal.
+    fn counter(&self, name: &str) -> Option<&Counter> {
+        if name == "list-item" {
+            Some(&self.list_item)
+        } else {
+            self.named.get(name)
+        }
+    }
+
+    fn counter_mut(&mut self, name: &str) -> &mut Counter {
+        if name == "list-item" {
+            &mut self.list_item
+        } else {
+            self.named
+                .entry(name.to_owned())
+                .or_insert_with(Counter::new)
+        }
+    }
+}
+
 /// A counter per CSS 2.1 § 12.4.
+#[derive(Default)]
 struct Counter {
     /// The values at each level.
     values: Vec<CounterValue>,
@@ -391,6 +541,19 @@ impl Counter {
         })
     }
 
+    fn set(&mut self, level: u32, value: i32) {
+        // Unlike `reset`, this changes the innermost instance of the counter, whatever its level.
+        if let Some(ref mut existing_value) = self.values.last_mut() {
+            existing_value.value = value;
+            return;
+        }
+
+        self.values.push(CounterValue {
+            level: level,
+            value: value,
+        })
+    }
+
     fn truncate_to_level(&mut self, level: u32) {
         if let Some(position) = self.values.iter().position(|value| value.level > level) {
             self.values.truncate(position)
@@ -418,6 +581,16 @@ impl Counter {
         list_style_type: ListStyleType,
         mode: RenderingMode,
     ) -> Option<SpecificFragmentInfo> {
+        let string = self.text(list_style_type, mode);
+        if string.is_empty() {
+            None
+        } else {
+            render_text(layout_context, node, pseudo, style, string)
+        }
+    }
+
+    /// Returns the text of this counter, rendered with the given style and mode.
+    fn text(&self, list_style_type: ListStyleType, mode: RenderingMode) -> String {
         let mut string = String::new();
         match mode {
             RenderingMode::Plain => {
@@ -427,12 +600,28 @@ impl Counter {
                 };
                 push_representation(value, list_style_type, &mut string)
             },
-            RenderingMode::Suffix(suffix) => {
+            RenderingMode::Marker(direction, separator) => {
+                let value = match self.values.last() {
+                    Some(ref value) => value.value,
+                    None => 0,
+                };
+                if let Some(marker) = format_marker(value, list_style_type, direction) {
+                    string.push_str(&marker);
+                    string.push_str(separator)
+                }
+            },
+            RenderingMode::Repeat(direction, symbol, suffix) => {
                 let value = match self.values.last() {
                     Some(ref value) => value.value,
                     None => 0,
                 };
-                push_representation(value, list_style_type, &mut string);
+                push_repeated_representation(
+                    value,
+                    symbol,
+                    list_style_type,
+                    direction,
+                    &mut string,
+                );
                 string.push_str(suffix)
             },
             RenderingMode::All(separator) => {
@@ -446,12 +635,7 @@ impl Counter {
                 }
             },
         }
-
-        if string.is_empty() {
-            None
-        } else {
-            render_text(layout_context, node, pseudo, style, string)
-        }
+        string
     }
 }
 
@@ -459,10 +643,14 @@ impl Counter {
 enum RenderingMode<'a> {
     /// The innermost counter value is rendered with no extra decoration.
     Plain,
-    /// The innermost counter value is rendered with the given string suffix.
-    Suffix(&'a str),
+    /// The innermost counter value is rendered as a list item marker by `format_marker` for the
+    /// given direction, followed by the given separator string.
+    Marker(Direction, &'a str),
     /// All values of the counter are rendered with the given separator string between them.
     All(&'a str),
+    /// The given symbol is repeated once per unit of the innermost counter value, followed by the
+    /// given string suffix. Values that can't be repeated are rendered as in `Marker`.
+    Repeat(Direction, char, &'a str),
 }
 
 /// The value of a counter at a given level.
@@ -481,6 +669,23 @@ fn render_text(
     style: crate::ServoArc<ComputedValues>,
     string: String,
 ) -> Option<SpecificFragmentInfo> {
+    // FIXME(pcwalton): This should properly handle multiple marker fragments. This could happen
+    // due to text run splitting.
+    render_text_fragments(layout_context, node, pseudo, style, string)
+        .into_iter()
+        .next()
+        .map(|fragment| fragment.specific)
+}
+
+/// Creates the scanned text fragments for a literal string. Text run splitting, e.g. at a change
+/// of bidi level, can make more than one.
+fn render_text_fragments(
+    layout_context: &LayoutContext,
+    node: OpaqueNode,
+    pseudo: PseudoElementType,
+    style: crate::ServoArc<ComputedValues>,
+    string: String,
+) -> LinkedList<Fragment> {
     let mut fragments = LinkedList::new();
     let info = SpecificFragmentInfo::UnscannedText(Box::new(UnscannedTextFragmentInfo::new(
         string.into_boxed_str(),
@@ -494,21 +699,46 @@ fn render_text(
         RestyleDamage::rebuild_and_reflow(),
         info,
     ));
-    // FIXME(pcwalton): This should properly handle multiple marker fragments. This could happen
-    // due to text run splitting.
-    let fragments = with_thread_local_font_context(layout_context, |font_context| {
+    with_thread_local_font_context(layout_context, |font_context| {
         TextRunScanner::new().scan_for_runs(font_context, fragments)
-    });
-    if fragments.is_empty() {
-        None
-    } else {
-        Some(fragments.fragments.into_iter().next().unwrap().specific)
+    })
+    .fragments
+}
+
+/// Returns the text of the list item marker for the given ordinal, e.g. "3." for `decimal` or "•"
+/// for `disc`, or `None` for `list-style-type: none`. This does not include the separator after
+/// inside markers; outside markers are separated by `marker_separation` instead.
+pub fn format_marker(
+    value: i32,
+    list_style_type: ListStyleType,
+    direction: Direction,
+) -> Option<String> {
+    let mut marker = String::new();
+    match list_style_type {
+        ListStyleType::None => return None,
+        ListStyleType::DisclosureClosed if direction == Direction::Rtl => marker.push('\u{25c2}'),
+        ListStyleType::Disc |
+        ListStyleType::Circle |
+        ListStyleType::Square |
+        ListStyleType::DisclosureOpen |
+        ListStyleType::DisclosureClosed => marker.push(static_representation(list_style_type)),
+        _ => {
+            push_representation(value, list_style_type, &mut marker);
+            // The suffix goes at the logical end, leaving its visual placement in right-to-left
+            // content to bidi reordering.
+            marker.push('.')
+        },
     }
+    Some(marker)
 }
 
 /// Appends string that represents the value rendered using the system appropriate for the given
 /// `list-style-type` onto the given string.
-fn push_representation(value: i32, list_style_type: ListStyleType, accumulator: &mut String) {
+///
+/// All of the predefined styles render any `i32` in a few dozen characters at most, so marker text
+/// is bounded without truncation. Author-defined `@counter-style` symbols, which Servo doesn't
+/// support yet, would need a length limit here (CSS-COUNTER-STYLES § 3.1.8 suggests one).
+pub fn push_representation(value: i32, list_style_type: ListStyleType, accumulator: &mut String) {
     match list_style_type {
         ListStyleType::None => {},
         ListStyleType::Disc |
@@ -516,7 +746,18 @@ fn push_representation(value: i32, list_style_type: ListStyleType, accumulator:
         ListStyleType::Square |
         ListStyleType::DisclosureOpen |
         ListStyleType::DisclosureClosed => accumulator.push(static_representation(list_style_type)),
+        ListStyleType::Decimal if pref!(layout.list_numbers.group_digits) => {
+            push_grouped_decimal_representation(value, accumulator)
+        },
         ListStyleType::Decimal => push_numeric_representation(value, &DECIMAL, accumulator),
+        ListStyleType::DecimalLeadingZero => {
+            // `decimal` padded to two digits with zeroes. A minus sign counts toward the width,
+            // so negative values are never padded.
+            if (0..10).contains(&value) {
+                accumulator.push(DECIMAL[0])
+            }
+            push_numeric_representation(value, &DECIMAL, accumulator)
+        },
         ListStyleType::ArabicIndic => {
             push_numeric_representation(value, &ARABIC_INDIC, accumulator)
         },
@@ -561,18 +802,35 @@ fn push_representation(value: i32, list_style_type: ListStyleType, accumulator:
         ListStyleType::KatakanaIroha => {
             push_alphabetic_representation(value, &KATAKANA_IROHA, accumulator)
         },
+        ListStyleType::LowerRoman => {
+            push_additive_representation(value, 1..=3999, &LOWER_ROMAN, accumulator)
+        },
+        ListStyleType::UpperRoman => {
+            push_additive_representation(value, 1..=3999, &UPPER_ROMAN, accumulator)
+        },
     }
 }
 
+/// Returns the gap between an outside marker and the content of its list item. This scales with
+/// the list item's font size, so the gap looks the same whatever the marker is: a bullet, a
+/// number, or an image. Inside markers instead end with a space, since they flow with the text.
+pub fn marker_separation(style: &ComputedValues) -> Au {
+    Au::from(style.get_font().font_size.computed_size()) / 2
+}
+
 /// Returns the static character that represents the value rendered using the given list-style, if
 /// possible.
+///
+/// These are the symbols of the predefined cyclic styles in CSS-COUNTER-STYLES § 6.2. Like other
+/// browsers, we draw them as ordinary glyphs of the marker's font, so they scale with `font-size`.
 pub fn static_representation(list_style_type: ListStyleType) -> char {
     match list_style_type {
-        ListStyleType::Disc => '•',
-        ListStyleType::Circle => '◦',
-        ListStyleType::Square => '▪',
-        ListStyleType::DisclosureOpen => '▾',
-        ListStyleType::DisclosureClosed => '‣',
+        ListStyleType::Disc => '\u{2022}',
+        ListStyleType::Circle => '\u{25e6}',
+        ListStyleType::Square => '\u{25aa}',
+        ListStyleType::DisclosureOpen => '\u{25be}',
+        // `format_marker` uses U+25C2 instead in right-to-left content.
+        ListStyleType::DisclosureClosed => '\u{25b8}',
         _ => panic!("No static representation for this list-style-type!"),
     }
 }
@@ -619,6 +877,83 @@ fn push_numeric_representation(value: i32, system: &[char], accumulator: &mut St
     accumulator.extend(string.iter().cloned().rev())
 }
 
+/// Pushes the `decimal` representation of the value onto the accumulator with a `,` between each
+/// group of three digits, e.g. "1,000". This is not part of CSS, so embedders opt into it with the
+/// `layout.list_numbers.group_digits` preference.
+pub fn push_grouped_decimal_representation(value: i32, accumulator: &mut String) {
+    if value < 0 {
+        accumulator.push('-');
+    }
+
+    let digits = value.unsigned_abs().to_string();
+    for (index, digit) in digits.chars().enumerate() {
+        if index != 0 && (digits.len() - index) % 3 == 0 {
+            accumulator.push(',');
+        }
+        accumulator.push(digit);
+    }
+}
+
+/// The largest value that `push_repeated_representation` spells out by repeating its symbol.
+const MAX_REPEATED_SYMBOLS: i32 = 16;
+
+/// Pushes the symbol onto the accumulator once per unit of the value, e.g. "•••" for 3. This is
+/// not part of CSS, so embedders opt into it with the `layout.list_numbers.repeat_symbols`
+/// preference. Values that can't be shown this way get the marker `format_marker` gives for the
+/// given `list-style-type` and direction instead, suffix included.
+pub fn push_repeated_representation(
+    value: i32,
+    symbol: char,
+    list_style_type: ListStyleType,
+    direction: Direction,
+    accumulator: &mut String,
+) {
+    if !(1..=MAX_REPEATED_SYMBOLS).contains(&value) {
+        if let Some(marker) = format_marker(value, list_style_type, direction) {
+            accumulator.push_str(&marker)
+        }
+        return;
+    }
+    accumulator.extend((0..value).map(|_| symbol))
+}
+
+/// Pushes the string that represents the value rendered using the given *additive system* onto
+/// the accumulator per CSS-COUNTER-STYLES § 3.1.8.
+///
+/// Values outside of `range` fall back to `decimal` per CSS-COUNTER-STYLES § 2.
+fn push_additive_representation(
+    value: i32,
+    range: RangeInclusive<i32>,
+    system: &[(usize, &str)],
+    accumulator: &mut String,
+) {
+    if !range.contains(&value) {
+        return push_numeric_representation(value, &DECIMAL, accumulator);
+    }
+
+    let mut abs_value = handle_negative_value(value, accumulator);
+
+    // Step 2.
+    for &(weight, symbol) in system {
+        // Step 3.1.
+        if abs_value == 0 {
+            break;
+        }
+
+        // Step 3.2.
+        if weight > abs_value {
+            continue;
+        }
+
+        // Step 3.3.
+        let reps = abs_value / weight;
+        for _ in 0..reps {
+            accumulator.push_str(symbol);
+        }
+        abs_value -= weight * reps;
+    }
+}
+
 /// If the system uses a negative sign, handle negative values per CSS-COUNTER-STYLES § 2.
 ///
 /// Returns the absolute value of the counter.
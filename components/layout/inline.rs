@@ -1247,6 +1247,21 @@ impl InlineFlow {
         )
     }
 
+    /// Returns the line metrics of a single empty line in the given style: that is, a line box
+    /// containing only the strut per CSS 2.1 § 10.8.1.
+    pub fn strut_line_metrics(
+        font_context: &mut LayoutFontContext,
+        style: &ComputedValues,
+    ) -> LineMetrics {
+        let font_metrics = text::font_metrics_for_style(font_context, style.clone_font());
+        let line_height = text::line_height_from_style(style, &font_metrics);
+        let inline_metrics = InlineMetrics::from_font_metrics(&font_metrics, line_height);
+        LineMetrics::new(
+            inline_metrics.space_above_baseline,
+            inline_metrics.space_below_baseline,
+        )
+    }
+
     /// Computes the minimum line metrics for the given fragments. This is typically done during
     /// flow construction.
     ///
@@ -1433,6 +1448,12 @@ impl InlineFlow {
         })
     }
 
+    pub fn baseline_offset_of_first_line(&self) -> Option<Au> {
+        self.first_line_containing_real_fragments().map(|line| {
+            line.bounds.start.b + line.bounds.size.block - line.metrics.space_below_baseline
+        })
+    }
+
     // Returns the last line that doesn't consist entirely of hypothetical boxes.
     fn last_line_containing_real_fragments(&self) -> Option<&Line> {
         for line in self.lines.iter().rev() {
@@ -1445,6 +1466,18 @@ impl InlineFlow {
         None
     }
 
+    // Returns the first line that doesn't consist entirely of hypothetical boxes.
+    fn first_line_containing_real_fragments(&self) -> Option<&Line> {
+        for line in self.lines.iter() {
+            if (line.range.begin().get()..line.range.end().get())
+                .any(|index| !self.fragments.fragments[index as usize].is_hypothetical())
+            {
+                return Some(line);
+            }
+        }
+        None
+    }
+
     fn build_display_list_for_inline_fragment_at_index(
         &mut self,
         state: &mut DisplayListBuildState,
@@ -2004,6 +2037,12 @@ impl Flow for InlineFlow {
         }
     }
 
+    fn iterate_fragments(&self, iterator: &mut dyn FnMut(&Fragment)) {
+        for fragment in &self.fragments.fragments {
+            (*iterator)(fragment)
+        }
+    }
+
     fn contains_positioned_fragments(&self) -> bool {
         self.fragments
             .fragments
@@ -17,11 +17,11 @@ pub mod flow;
 mod flow_list;
 pub mod flow_ref;
 mod fragment;
-mod generated_content;
+pub mod generated_content;
 pub mod incremental;
 mod inline;
 mod linked_list;
-mod list_item;
+pub mod list_item;
 mod model;
 mod multicol;
 pub mod parallel;
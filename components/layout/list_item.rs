@@ -5,11 +5,20 @@
 //! Layout for elements with a CSS `display` property of `list-item`. These elements consist of a
 //! block and an extra inline fragment for the marker.
 
+use std::cmp::max;
+use std::collections::LinkedList;
+use std::mem;
+
 use app_units::Au;
 use euclid::default::Point2D;
+use gfx_traits::print_tree::PrintTree;
+use log::trace;
+use servo_config::{opts, pref};
+use style::computed_values::direction::T as Direction;
+use style::computed_values::list_style_position::T as ListStylePosition;
 use style::computed_values::list_style_type::T as ListStyleType;
 use style::computed_values::position::T as Position;
-use style::logical_geometry::LogicalSize;
+use style::logical_geometry::{LogicalSize, WritingMode};
 use style::properties::ComputedValues;
 use style::servo::restyle_damage::ServoRestyleDamage;
 
@@ -20,12 +29,14 @@ use crate::display_list::{
     BorderPaintingMode, DisplayListBuildState, StackingContextCollectionState,
 };
 use crate::floats::FloatKind;
-use crate::flow::{Flow, FlowClass, OpaqueFlow};
+use crate::flow::{Flow, FlowClass, FlowFlags, ImmutableFlowUtils, OpaqueFlow};
 use crate::fragment::{
-    CoordinateSystem, Fragment, FragmentBorderBoxIterator, GeneratedContentInfo, Overflow,
+    Fragment, FragmentBorderBoxIterator, GeneratedContentInfo, Overflow, SpecificFragmentInfo,
+    UnscannedTextFragmentInfo,
 };
 use crate::generated_content;
 use crate::inline::InlineFlow;
+use crate::text::TextRunScanner;
 
 #[allow(unsafe_code)]
 unsafe impl crate::flow::HasBaseFlow for ListItemFlow {}
@@ -38,6 +49,10 @@ pub struct ListItemFlow {
     pub block_flow: BlockFlow,
     /// The marker, if outside. (Markers that are inside are instead just fragments on the interior
     /// `InlineFlow`.)
+    ///
+    /// The list item owns its marker exactly once, so fragmenting its content (e.g. across
+    /// columns) never duplicates the marker. `column-span` is not supported by Servo yet; when it
+    /// is, the marker must stay with the list item's first fragment rather than a spanner.
     pub marker_fragments: Vec<Fragment>,
 }
 
@@ -47,11 +62,24 @@ impl ListItemFlow {
         marker_fragments: Vec<Fragment>,
         flotation: Option<FloatKind>,
     ) -> ListItemFlow {
+        // Markers are built from the list item's own style, so a marker whose list style
+        // disagrees with the list item's means the fragments were put together incorrectly.
+        debug_assert!(
+            marker_fragments.iter().all(|marker| {
+                marker.style().get_list().list_style_type ==
+                    main_fragment.style().get_list().list_style_type
+            }),
+            "Marker fragments must share the list style of their list item"
+        );
+
         let mut this = ListItemFlow {
             block_flow: BlockFlow::from_fragment_and_float_kind(main_fragment, flotation),
             marker_fragments: marker_fragments,
         };
 
+        // Numbered markers start out as unresolved generated content. The layout thread resolves
+        // generated content before computing any intrinsic sizes in the same reflow, so an
+        // unresolved marker is never measured and the content never shifts once it's resolved.
         if let Some(ref marker) = this.marker_fragments.first() {
             match marker.style().get_list().list_style_type {
                 ListStyleType::Disc |
@@ -60,43 +88,82 @@ impl ListItemFlow {
                 ListStyleType::Square |
                 ListStyleType::DisclosureOpen |
                 ListStyleType::DisclosureClosed => {},
-                _ => this
-                    .block_flow
-                    .base
-                    .restyle_damage
-                    .insert(ServoRestyleDamage::RESOLVE_GENERATED_CONTENT),
+                list_style_type => {
+                    trace!(
+                        "ListItemFlow: marker with list-style-type {:?} needs generated content",
+                        list_style_type
+                    );
+                    this.block_flow
+                        .base
+                        .restyle_damage
+                        .insert(ServoRestyleDamage::RESOLVE_GENERATED_CONTENT)
+                },
             }
         }
 
         this
     }
 
-    /// Assign inline size and position for the marker. This is done during the `assign_block_size`
-    /// traversal because floats will impact the marker position. Therefore we need to have already
-    /// called `assign_block_size` on the list item's block flow, in order to know which floats
-    /// impact the position.
+    /// Returns the inline position that the markers hang from. This is computed during the
+    /// `assign_block_size` traversal because floats will impact the marker position. Therefore we
+    /// need to have already called `assign_block_size` on the list item's block flow, in order to
+    /// know which floats impact the position.
     ///
     /// Per CSS 2.1 § 12.5.1, the marker position is not precisely specified, but it must be on the
     /// left side of the content (for ltr direction). However, flowing the marker around floats
     /// matches the rendering of Gecko and Blink.
-    fn assign_marker_inline_sizes(&mut self, layout_context: &LayoutContext) {
+    ///
+    /// The marker is positioned against the list item's block edge, not its first line box, so
+    /// `text-indent` on the list item indents the content but never shifts the marker.
+    fn marker_inline_end(&self) -> Au {
         let base = &self.block_flow.base;
-        let available_rect = base.floats.available_rect(
-            -base.position.size.block,
-            base.position.size.block,
-            base.block_container_inline_size,
-        );
-        let mut marker_inline_start = available_rect
+        let available_rect = if !floats_affect_markers(
+            base.flags.contains(FlowFlags::IS_ABSOLUTELY_POSITIONED),
+            base.writing_mode,
+            base.floats.writing_mode,
+        ) || opts::get().debug.markers_ignore_floats
+        {
+            None
+        } else {
+            // Overconstrained layouts can briefly give the list item a negative containing block
+            // size, which would put the end of the rect, and so its start, somewhere wild.
+            base.floats.available_rect(
+                -base.position.size.block,
+                base.position.size.block,
+                max(base.block_container_inline_size, Au(0)),
+            )
+        };
+        // `available_rect` is logical, so in `direction: rtl` its start edge is the right edge and
+        // only floats on the inline-start side move the marker; end-side floats narrow the rect
+        // from the other side and leave the start edge alone. (Legacy layout places
+        // `FloatKind::Left` floats at the logical inline start.)
+        available_rect
             .unwrap_or(self.block_flow.fragment.border_box)
             .start
-            .i;
+            .i
+    }
 
+    /// Assign inline size and position for the markers, placing them end to start from
+    /// `marker_inline_end`.
+    fn assign_marker_inline_sizes(
+        &mut self,
+        layout_context: &LayoutContext,
+        marker_inline_end: Au,
+    ) {
+        // Percentage-sized image markers must not turn a negative containing block size (see
+        // `marker_inline_end`) into a negative marker size.
+        let block_container_inline_size =
+            max(self.block_flow.base.block_container_inline_size, Au(0));
+        let container_block_size = self
+            .block_flow
+            .explicit_block_containing_size(layout_context.shared_context());
+        // The marker box nearest the content carries the gap to the content as its inline-end
+        // margin, so the markers' margin boxes span exactly from their start to the content edge.
+        let mut separation = generated_content::marker_separation(&*self.block_flow.fragment.style);
+        let mut marker_inline_start = marker_inline_end;
         for marker in self.marker_fragments.iter_mut().rev() {
-            let container_block_size = self
-                .block_flow
-                .explicit_block_containing_size(layout_context.shared_context());
             marker.assign_replaced_inline_size_if_necessary(
-                base.block_container_inline_size,
+                block_container_inline_size,
                 container_block_size,
             );
 
@@ -107,14 +174,40 @@ impl ListItemFlow {
             marker.border_box.size.inline = intrinsic_inline_sizes
                 .content_intrinsic_sizes
                 .preferred_inline_size;
-            marker_inline_start = marker_inline_start - marker.border_box.size.inline;
-            marker.border_box.start.i = marker_inline_start;
+            marker.margin.inline_start = Au(0);
+            marker.margin.inline_end = mem::replace(&mut separation, Au(0));
+
+            // Markers hang off the start edge and grow toward the inline start, so the content of
+            // a "9." item and a "10." item begins at the same position; no column needs reserving.
+            marker_inline_start = marker_inline_start - marker.margin_box_inline_size();
+            marker.border_box.start.i = marker_inline_start + marker.margin.inline_start;
+            debug_assert!(
+                marker.border_box.start.i + marker.border_box.size.inline <= marker_inline_end,
+                "Markers must hang before their inline end"
+            );
+
+            layout_context.statistics.record_marker_laid_out();
         }
     }
 
     fn assign_marker_block_sizes(&mut self, layout_context: &LayoutContext) {
+        if self.marker_fragments.is_empty() {
+            return;
+        }
+
         // FIXME(pcwalton): Do this during flow construction, like `InlineFlow` does?
+        layout_context
+            .statistics
+            .record_marker_font_context_invocation();
         let marker_line_metrics = with_thread_local_font_context(layout_context, |font_context| {
+            // An empty list item has no line boxes for the marker to sit on, so synthesize a
+            // single empty line in the list item's style instead.
+            if self.block_flow.base.children.is_empty() {
+                return InlineFlow::strut_line_metrics(
+                    font_context,
+                    &*self.block_flow.fragment.style,
+                );
+            }
             InlineFlow::minimum_line_metrics_for_fragments(
                 &self.marker_fragments,
                 font_context,
@@ -122,15 +215,30 @@ impl ListItemFlow {
             )
         });
 
+        let first_line_baseline =
+            (&self.block_flow as &dyn Flow).baseline_offset_of_first_line_box_in_flow();
+
+        // TODO: Honor `alignment-baseline` and `dominant-baseline` (e.g. `central`) from a
+        // `::marker` style here. Servo supports neither the pseudo-element nor the properties yet,
+        // so markers always align on the alphabetic baseline.
         for marker in &mut self.marker_fragments {
             marker.assign_replaced_block_size_if_necessary();
+            // Text markers look up their font metrics.
+            if let SpecificFragmentInfo::ScannedText(..) = marker.specific {
+                layout_context
+                    .statistics
+                    .record_marker_font_context_invocation();
+            }
             let marker_inline_metrics = marker.aligned_inline_metrics(
                 layout_context,
                 &marker_line_metrics,
                 Some(&marker_line_metrics),
             );
-            marker.border_box.start.b =
-                marker_line_metrics.space_above_baseline - marker_inline_metrics.ascent;
+            marker.border_box.start.b = marker_block_start(
+                first_line_baseline,
+                marker_line_metrics.space_above_baseline,
+                marker_inline_metrics.ascent,
+            );
         }
     }
 }
@@ -148,8 +256,23 @@ impl Flow for ListItemFlow {
         &self.block_flow
     }
 
+    fn as_list_item(&self) -> Option<&ListItemFlow> {
+        Some(self)
+    }
+
+    fn as_mut_list_item(&mut self) -> Option<&mut ListItemFlow> {
+        Some(self)
+    }
+
     fn bubble_inline_sizes(&mut self) {
-        // The marker contributes no intrinsic inline-size, so…
+        // Outside markers contribute no intrinsic inline-size, so… Inside markers are ordinary
+        // fragments of the first inline flow, which already counts them in its preferred
+        // inline-size; shrink-to-fit sizing therefore uses the same measurement. An inside image
+        // marker is a replaced fragment there, so its intrinsic width counts toward both the
+        // minimum and the preferred inline-size.
+        //
+        // TODO: `inline-size: max-content` is only parsed for Gecko. Once Servo supports it,
+        // it must use these same intrinsic sizes.
         self.block_flow.bubble_inline_sizes()
     }
 
@@ -159,7 +282,12 @@ impl Flow for ListItemFlow {
 
     fn assign_block_size(&mut self, layout_context: &LayoutContext) {
         self.block_flow.assign_block_size(layout_context);
-        self.assign_marker_inline_sizes(layout_context);
+        let mut marker_inline_end = self.marker_inline_end();
+        if pref!(layout.list_markers.anchor_to_content_box) {
+            marker_inline_end =
+                marker_inline_end + self.block_flow.fragment.border_padding.inline_start;
+        }
+        self.assign_marker_inline_sizes(layout_context, marker_inline_end);
         self.assign_marker_block_sizes(layout_context);
     }
 
@@ -191,12 +319,21 @@ impl Flow for ListItemFlow {
     }
 
     fn build_display_list(&mut self, state: &mut DisplayListBuildState) {
-        // Draw the marker, if applicable.
+        // Draw the marker, if applicable. Text markers are painted like any other text fragment,
+        // so they pick up the list item's inherited `text-shadow`. Markers are clipped only by
+        // our ancestors, never by the list item's own overflow clip, so they stay visible however
+        // the content is clipped.
+        //
+        // TODO: `-webkit-line-clamp` is Gecko-only in the style system; if Servo gains it, the
+        // clamp must not hide the marker either. Likewise, a `::marker { clip-path: ... }` would
+        // need its own clip here once the `::marker` pseudo-element exists.
         for marker in &mut self.marker_fragments {
             let stacking_relative_border_box = self
                 .block_flow
                 .base
                 .stacking_relative_border_box_for_display_list(marker);
+            // `Fragment::build_display_list` emits nothing for a marker entirely outside the
+            // clip, so off-screen markers cost only this position computation.
             marker.build_display_list(
                 state,
                 stacking_relative_border_box,
@@ -217,11 +354,33 @@ impl Flow for ListItemFlow {
     }
 
     fn repair_style(&mut self, new_style: &crate::ServoArc<ComputedValues>) {
-        self.block_flow.repair_style(new_style)
+        // A `direction` change rebuilds this flow, since the writing mode is fixed at
+        // construction, so markers never need to move sides here; the next reflow places them.
+        //
+        // `repair_if_possible` only repairs block flows in place, so an in-flow list item is
+        // rebuilt, markers included, on any restyle. This only runs for absolutely positioned
+        // list items whose hypothetical box is inline. Their markers share the list item's style,
+        // so a repaint-only change such as `color` must reach them too.
+        //
+        // `list-style-type` changes (e.g. `disc` to `decimal`) carry `RECONSTRUCT_FLOW` damage,
+        // so the new flow is built with the right kind of marker and generated content damage.
+        debug_assert_eq!(
+            self.block_flow.fragment.style().get_list().list_style_type,
+            new_style.get_list().list_style_type,
+            "A list-style-type change must rebuild the list item flow"
+        );
+        self.block_flow.repair_style(new_style);
+        for marker in &mut self.marker_fragments {
+            marker.repair_style(new_style)
+        }
     }
 
     fn compute_overflow(&self) -> Overflow {
         let mut overflow = self.block_flow.compute_overflow();
+        if self.marker_fragments.is_empty() {
+            return overflow;
+        }
+
         let flow_size = self
             .block_flow
             .base
@@ -234,6 +393,8 @@ impl Flow for ListItemFlow {
             .early_absolute_position_info
             .relative_containing_block_size;
 
+        // Markers share the list item's style, so `Fragment::compute_overflow` offsets them by
+        // the same relative position as the list item's own fragment; no extra offset is needed.
         for fragment in &self.marker_fragments {
             overflow.union(&fragment.compute_overflow(&flow_size, &relative_containing_block_size))
         }
@@ -261,25 +422,17 @@ impl Flow for ListItemFlow {
             stacking_context_position,
         );
 
+        // Report the same box that `build_display_list` paints the marker at. Both run after
+        // `assign_block_size` has aligned the markers.
         for marker in &self.marker_fragments {
             if iterator.should_process(marker) {
                 iterator.process(
                     marker,
                     level,
-                    &marker
-                        .stacking_relative_border_box(
-                            &self.block_flow.base.stacking_relative_position,
-                            &self
-                                .block_flow
-                                .base
-                                .early_absolute_position_info
-                                .relative_containing_block_size,
-                            self.block_flow
-                                .base
-                                .early_absolute_position_info
-                                .relative_containing_block_mode,
-                            CoordinateSystem::Own,
-                        )
+                    &self
+                        .block_flow
+                        .base
+                        .stacking_relative_border_box_for_display_list(marker)
                         .translate(stacking_context_position.to_vector()),
                 );
             }
@@ -293,20 +446,75 @@ impl Flow for ListItemFlow {
             (*mutator)(marker)
         }
     }
+
+    fn iterate_fragments(&self, iterator: &mut dyn FnMut(&Fragment)) {
+        self.block_flow.iterate_fragments(iterator);
+
+        for marker in &self.marker_fragments {
+            (*iterator)(marker)
+        }
+    }
+
+    fn print_extra_flow_children(&self, print_tree: &mut PrintTree) {
+        self.block_flow.print_extra_flow_children(print_tree);
+
+        for marker in &self.marker_fragments {
+            print_tree.add_item(format!("↑↑ Marker fragment for list item:{:?}", marker));
+        }
+    }
+}
+
+/// Returns true if the floats around a list item, laid out in `floats_writing_mode`, can move the
+/// markers of a list item in `writing_mode`.
+///
+/// Absolutely positioned list items, `position: fixed` ones included (both set
+/// `IS_ABSOLUTELY_POSITIONED`), are taken out of flow, so the floats around their static position
+/// don't affect them; their markers are placed against the item itself. Floats laid out in a
+/// container with a different `direction` or writing mode measure their inline start from the other
+/// side, so they can't be compared with the list item's border box either. Each list item places
+/// its markers in its own writing mode, so a list whose items have mixed writing modes needs no
+/// special handling.
+///
+/// FIXME: Convert the available rect into the list item's writing mode instead of ignoring floats.
+pub fn floats_affect_markers(
+    is_absolutely_positioned: bool,
+    writing_mode: WritingMode,
+    floats_writing_mode: WritingMode,
+) -> bool {
+    !is_absolutely_positioned && floats_writing_mode == writing_mode
+}
+
+/// Returns the block-start position, in the list item's own coordinates, of a marker with the given
+/// ascent.
+///
+/// Markers sit on the baseline of the list item's first line box, which may be pushed down by
+/// padding, a `::before`, or a nested block. Without one, they sit on `synthesized_baseline`, the
+/// baseline of a line laid out for the markers alone. Since the position is relative to the list
+/// item, anything that moves the whole list item, like the `vertical-align` of a table cell
+/// around it, moves its markers along with its content.
+pub fn marker_block_start(
+    first_line_baseline: Option<Au>,
+    synthesized_baseline: Au,
+    marker_ascent: Au,
+) -> Au {
+    first_line_baseline.unwrap_or(synthesized_baseline) - marker_ascent
 }
 
 /// The kind of content that `list-style-type` results in.
 pub enum ListStyleTypeContent {
     None,
-    StaticText(char),
+    StaticText(String),
     GeneratedContent(Box<GeneratedContentInfo>),
 }
 
 impl ListStyleTypeContent {
     /// Returns the content to be used for the given value of the `list-style-type` property.
-    pub fn from_list_style_type(list_style_type: ListStyleType) -> ListStyleTypeContent {
-        // Just to keep things simple, use a nonbreaking space (Unicode 0xa0) to provide the marker
-        // separation.
+    pub fn from_list_style_type(
+        list_style_type: ListStyleType,
+        direction: Direction,
+    ) -> ListStyleTypeContent {
+        // The returned text carries no separation from the content: outside markers get a margin
+        // (see `assign_marker_inline_sizes`), and inside ones a trailing no-break space.
         match list_style_type {
             ListStyleType::None => ListStyleTypeContent::None,
             ListStyleType::Disc |
@@ -314,10 +522,61 @@ impl ListStyleTypeContent {
             ListStyleType::Square |
             ListStyleType::DisclosureOpen |
             ListStyleType::DisclosureClosed => {
-                let text = generated_content::static_representation(list_style_type);
-                ListStyleTypeContent::StaticText(text)
+                // Symbols of the cyclic styles don't depend on the ordinal.
+                generated_content::format_marker(1, list_style_type, direction)
+                    .map_or(ListStyleTypeContent::None, ListStyleTypeContent::StaticText)
             },
             _ => ListStyleTypeContent::GeneratedContent(Box::new(GeneratedContentInfo::ListItem)),
         }
     }
 }
+
+/// Builds the marker fragments that a list item's `list-style-type` calls for. `new_fragment` creates a fragment of the list item with the given specific info.
+/// `list-style-image` markers need the list item's DOM node, so they are built by the caller.
+pub fn build_marker_fragments<F>(
+    layout_context: &LayoutContext,
+    style: &ComputedValues,
+    mut new_fragment: F,
+) -> Vec<Fragment>
+where
+    F: FnMut(SpecificFragmentInfo) -> Fragment,
+{
+    match ListStyleTypeContent::from_list_style_type(
+        style.get_list().list_style_type,
+        style.get_inherited_box().direction,
+    ) {
+        ListStyleTypeContent::None => Vec::new(),
+        ListStyleTypeContent::StaticText(text) => {
+            // The marker is shaped with the list item's own style, so bullets scale with the list
+            // item's `font-size` just as an unstyled `::marker` would. It has no DOM text and no
+            // insertion point, so the caret never lands inside it.
+            //
+            // Outside markers are separated from the content by
+            // `generated_content::marker_separation` instead of a trailing space.
+            let text = match style.get_list().list_style_position {
+                ListStylePosition::Outside => text,
+                ListStylePosition::Inside => format!("{}\u{a0}", text),
+            };
+            // The marker is scanned on its own, so its bidi levels are resolved without the list
+            // item's text. That matches the `unicode-bidi: isolate` of the UA `::marker` style; an
+            // author `unicode-bidi` needs `::marker` support.
+            let mut unscanned_marker_fragments = LinkedList::new();
+            unscanned_marker_fragments.push_back(new_fragment(
+                SpecificFragmentInfo::UnscannedText(Box::new(UnscannedTextFragmentInfo::new(
+                    Box::<str>::from(text),
+                    None,
+                ))),
+            ));
+            layout_context
+                .statistics
+                .record_marker_font_context_invocation();
+            with_thread_local_font_context(layout_context, |font_context| {
+                TextRunScanner::new().scan_for_runs(font_context, unscanned_marker_fragments)
+            })
+            .fragments
+        },
+        ListStyleTypeContent::GeneratedContent(info) => {
+            vec![new_fragment(SpecificFragmentInfo::GeneratedContent(info))]
+        },
+    }
+}
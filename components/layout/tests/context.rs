@@ -0,0 +1,28 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use ipc_channel::ipc;
+use layout_2013::context::LayoutStatistics;
+use profile_traits::time::{ProfilerChan, ProfilerMsg};
+
+#[test]
+fn test_list_marker_statistics_are_reported_to_the_time_profiler() {
+    let (sender, receiver) = ipc::channel().unwrap();
+    let time_profiler_chan = ProfilerChan(sender);
+
+    // Layouts without markers don't bother the profiler.
+    let statistics = LayoutStatistics::default();
+    statistics.report(&time_profiler_chan);
+    assert!(receiver.try_recv().is_err());
+
+    for _ in 0..3 {
+        statistics.record_marker_laid_out();
+    }
+    statistics.record_marker_font_context_invocation();
+    statistics.report(&time_profiler_chan);
+    match receiver.recv().unwrap() {
+        ProfilerMsg::ListMarkers(3, 1) => {},
+        msg => panic!("unexpected profiler message {:?}", msg),
+    }
+}
@@ -0,0 +1,215 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use app_units::Au;
+use euclid::{Scale, Size2D};
+use layout_2013::generated_content::{
+    format_marker, marker_separation, push_grouped_decimal_representation,
+    push_repeated_representation, push_representation, Counters,
+};
+use style::computed_values::direction::T as Direction;
+use style::computed_values::list_style_type::T as ListStyleType;
+use style::context::QuirksMode;
+use style::media_queries::{Device, MediaType};
+use style::properties::StyleBuilder;
+use style::values::computed::font::FontSize;
+use style::values::computed::NonNegativeLength;
+use style::values::generics::counters::CounterPair;
+use style::values::specified::font::KeywordInfo;
+use style::values::CustomIdent;
+use style::Atom;
+
+fn representation(value: i32, list_style_type: ListStyleType) -> String {
+    let mut string = String::new();
+    push_representation(value, list_style_type, &mut string);
+    string
+}
+
+#[test]
+fn test_roman_numerals() {
+    assert_eq!(representation(1, ListStyleType::LowerRoman), "i");
+    assert_eq!(representation(4, ListStyleType::LowerRoman), "iv");
+    assert_eq!(representation(14, ListStyleType::LowerRoman), "xiv");
+    assert_eq!(representation(1994, ListStyleType::UpperRoman), "MCMXCIV");
+    assert_eq!(representation(3999, ListStyleType::UpperRoman), "MMMCMXCIX");
+}
+
+#[test]
+fn test_roman_numerals_out_of_range_fall_back_to_decimal() {
+    assert_eq!(representation(0, ListStyleType::LowerRoman), "0");
+    assert_eq!(representation(-3, ListStyleType::LowerRoman), "-3");
+    assert_eq!(representation(4000, ListStyleType::UpperRoman), "4000");
+}
+
+#[test]
+fn test_decimal_leading_zero() {
+    assert_eq!(representation(0, ListStyleType::DecimalLeadingZero), "00");
+    assert_eq!(representation(7, ListStyleType::DecimalLeadingZero), "07");
+    assert_eq!(representation(10, ListStyleType::DecimalLeadingZero), "10");
+    assert_eq!(
+        representation(123, ListStyleType::DecimalLeadingZero),
+        "123"
+    );
+    assert_eq!(representation(-5, ListStyleType::DecimalLeadingZero), "-5");
+}
+
+#[test]
+fn test_grouped_decimal() {
+    let grouped = |value| {
+        let mut string = String::new();
+        push_grouped_decimal_representation(value, &mut string);
+        string
+    };
+    assert_eq!(grouped(0), "0");
+    assert_eq!(grouped(999), "999");
+    assert_eq!(grouped(1000), "1,000");
+    assert_eq!(grouped(1234567), "1,234,567");
+    assert_eq!(grouped(-1000), "-1,000");
+    assert_eq!(grouped(i32::MIN), "-2,147,483,648");
+}
+
+#[test]
+fn test_repeated_symbols() {
+    let repeated = |value, list_style_type| {
+        let mut string = String::new();
+        push_repeated_representation(value, '*', list_style_type, Direction::Ltr, &mut string);
+        string
+    };
+    assert_eq!(repeated(1, ListStyleType::Decimal), "*");
+    assert_eq!(repeated(3, ListStyleType::Decimal), "***");
+    assert_eq!(repeated(16, ListStyleType::Decimal), "*".repeat(16));
+    // Values that can't be spelled out fall back to the given list-style-type, with its suffix.
+    assert_eq!(repeated(17, ListStyleType::Decimal), "17.");
+    assert_eq!(repeated(17, ListStyleType::LowerRoman), "xvii.");
+    assert_eq!(repeated(0, ListStyleType::Decimal), "0.");
+    assert_eq!(repeated(-2, ListStyleType::Decimal), "-2.");
+    assert_eq!(repeated(17, ListStyleType::Disc), "\u{2022}");
+}
+
+#[test]
+fn test_format_marker() {
+    assert_eq!(format_marker(1, ListStyleType::None, Direction::Ltr), None);
+    assert_eq!(
+        format_marker(5, ListStyleType::Disc, Direction::Ltr),
+        Some("\u{2022}".to_owned())
+    );
+    assert_eq!(
+        format_marker(1, ListStyleType::DisclosureClosed, Direction::Ltr),
+        Some("\u{25b8}".to_owned())
+    );
+    assert_eq!(
+        format_marker(1, ListStyleType::DisclosureClosed, Direction::Rtl),
+        Some("\u{25c2}".to_owned())
+    );
+    assert_eq!(
+        format_marker(3, ListStyleType::Decimal, Direction::Ltr),
+        Some("3.".to_owned())
+    );
+    // The suffix stays at the logical end; bidi reordering places it in right-to-left content.
+    assert_eq!(
+        format_marker(3, ListStyleType::Decimal, Direction::Rtl),
+        Some("3.".to_owned())
+    );
+    assert_eq!(
+        format_marker(27, ListStyleType::LowerAlpha, Direction::Ltr),
+        Some("aa.".to_owned())
+    );
+    assert_eq!(
+        format_marker(9, ListStyleType::DecimalLeadingZero, Direction::Ltr),
+        Some("09.".to_owned())
+    );
+}
+
+fn pair(name: &str, value: i32) -> CounterPair<i32> {
+    CounterPair {
+        name: CustomIdent(Atom::from(name)),
+        value,
+        is_reversed: false,
+    }
+}
+
+#[test]
+fn test_list_items_increment_list_item_whatever_their_style() {
+    // `list-style-type` only affects how the marker looks, so `counter(list-item)` counts the
+    // items of a `ul` too.
+    let mut counters = Counters::new();
+    counters.update(1, false, &[pair("list-item", 0)], &[], &[]);
+    for ordinal in 1..=3 {
+        counters.update(2, true, &[], &[], &[]);
+        assert_eq!(counters.value("list-item"), ordinal);
+    }
+}
+
+#[test]
+fn test_counter_increment_replaces_implicit_list_item_increment() {
+    let mut counters = Counters::new();
+    counters.update(1, false, &[pair("list-item", 0)], &[], &[]);
+    counters.update(2, true, &[], &[pair("list-item", 2)], &[]);
+    assert_eq!(counters.value("list-item"), 2);
+    counters.update(2, true, &[], &[pair("list-item", 2)], &[]);
+    assert_eq!(counters.value("list-item"), 4);
+    // Incrementing another counter keeps the implicit increment.
+    counters.update(2, true, &[], &[pair("item", 5)], &[]);
+    assert_eq!(counters.value("list-item"), 5);
+    assert_eq!(counters.value("item"), 5);
+}
+
+#[test]
+fn test_lists_reset_list_item() {
+    // <ol><div><li></li></div><div><li></li></div><li><ol><li></li></ol></li></ol>
+    let mut counters = Counters::new();
+    counters.update(1, false, &[pair("list-item", 0)], &[], &[]);
+    counters.update(2, false, &[], &[], &[]);
+    counters.update(3, true, &[], &[], &[]);
+    assert_eq!(counters.value("list-item"), 1);
+    counters.update(2, false, &[], &[], &[]);
+    counters.update(3, true, &[], &[], &[]);
+    assert_eq!(counters.value("list-item"), 2);
+    counters.update(2, true, &[], &[], &[]);
+    assert_eq!(counters.value("list-item"), 3);
+    counters.update(3, false, &[pair("list-item", 0)], &[], &[]);
+    counters.update(4, true, &[], &[], &[]);
+    assert_eq!(counters.value("list-item"), 1);
+    counters.update(2, true, &[], &[], &[]);
+    assert_eq!(counters.value("list-item"), 4);
+}
+
+#[test]
+fn test_counter_set() {
+    let mut counters = Counters::new();
+    counters.update(1, false, &[pair("item", 0)], &[], &[]);
+    counters.update(2, false, &[], &[pair("item", 1)], &[pair("item", 10)]);
+    assert_eq!(
+        representation(counters.value("item"), ListStyleType::Decimal),
+        "10"
+    );
+    // `counter-set` changes the existing instance rather than creating a nested one.
+    counters.update(2, false, &[], &[pair("item", 1)], &[]);
+    assert_eq!(counters.value("item"), 11);
+    // A counter that isn't in scope is instantiated by `counter-set`.
+    counters.update(2, false, &[], &[], &[pair("other", 10)]);
+    assert_eq!(counters.value("other"), 10);
+}
+
+#[test]
+fn test_marker_separation_scales_with_font_size() {
+    let device = Device::new(
+        MediaType::screen(),
+        QuirksMode::NoQuirks,
+        Size2D::new(800.0, 600.0),
+        Scale::new(1.0),
+    );
+    let separation = |px| {
+        let mut builder = StyleBuilder::for_inheritance(&device, None, None);
+        builder.mutate_font().set_font_size(FontSize {
+            computed_size: NonNegativeLength::new(px),
+            used_size: NonNegativeLength::new(px),
+            keyword_info: KeywordInfo::none(),
+        });
+        marker_separation(&builder.build())
+    };
+    assert_eq!(separation(16.0), Au::from_px(8));
+    assert_eq!(separation(32.0), Au::from_px(16));
+    assert_eq!(separation(0.0), Au(0));
+}
@@ -0,0 +1,61 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use app_units::Au;
+use layout_2013::list_item::{floats_affect_markers, marker_block_start};
+use style::logical_geometry::WritingMode;
+
+#[test]
+fn test_markers_of_mixed_writing_mode_items() {
+    // <ul style="writing-mode: horizontal-tb">
+    //   <li></li>
+    //   <li style="writing-mode: vertical-rl"></li>
+    //   <li style="writing-mode: vertical-lr"></li>
+    //   <li style="direction: rtl"></li>
+    // </ul>
+    let list = WritingMode::empty();
+    let vertical_rl = WritingMode::VERTICAL;
+    let vertical_lr = WritingMode::VERTICAL | WritingMode::VERTICAL_LR | WritingMode::LINE_INVERTED;
+    let rtl = WritingMode::RTL | WritingMode::INLINE_REVERSED;
+
+    assert!(floats_affect_markers(false, list, list));
+    // Items in another writing mode place their markers against themselves.
+    assert!(!floats_affect_markers(false, vertical_rl, list));
+    assert!(!floats_affect_markers(false, vertical_lr, list));
+    assert!(!floats_affect_markers(false, rtl, list));
+    // Items in a vertical list that share its writing mode flow around its floats as usual.
+    assert!(floats_affect_markers(false, vertical_rl, vertical_rl));
+}
+
+#[test]
+fn test_markers_of_top_layer_list_items() {
+    let horizontal_tb = WritingMode::empty();
+    // <dialog open><ol><li></li></ol></dialog>: the dialog is in the top layer, but its list is
+    // laid out like any other, so the list's floats move the item's marker.
+    assert!(floats_affect_markers(false, horizontal_tb, horizontal_tb));
+    // <li> in the top layer: the style adjuster makes it absolutely positioned, so its marker is
+    // placed against the item itself.
+    assert!(!floats_affect_markers(true, horizontal_tb, horizontal_tb));
+}
+
+#[test]
+fn test_marker_follows_list_item_in_bottom_aligned_table_cell() {
+    // <td style="height: 100px; vertical-align: bottom"><ul><li>Item</li></ul></td>
+    //
+    // The cell moves the whole list item down by the space left below its content. The marker is
+    // positioned in the list item's own coordinates, against the baseline of the item's first
+    // line, so it moves along and doesn't depend on the cell's alignment.
+    assert_eq!(
+        marker_block_start(Some(Au::from_px(12)), Au(0), Au::from_px(10)),
+        Au::from_px(2)
+    );
+}
+
+#[test]
+fn test_marker_of_empty_list_item_sits_on_synthesized_baseline() {
+    assert_eq!(
+        marker_block_start(None, Au::from_px(14), Au::from_px(10)),
+        Au::from_px(4)
+    );
+}
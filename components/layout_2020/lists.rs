@@ -2,6 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use style::logical_geometry::WritingMode;
 use style::properties::longhands::list_style_type::computed_value::T as ListStyleType;
 use style::properties::style_structs;
 use style::values::computed::Image;
@@ -39,21 +40,23 @@ where
     };
     marker_image().or_else(|| {
         Some(vec![PseudoElementContentItem::Text(
-            marker_string(style)?.into(),
+            marker_string(style, info.style.writing_mode)?.into(),
         )])
     })
 }
 
 /// <https://drafts.csswg.org/css-lists/#marker-string>
-fn marker_string(style: &style_structs::List) -> Option<&'static str> {
+fn marker_string(style: &style_structs::List, writing_mode: WritingMode) -> Option<&'static str> {
     match style.list_style_type {
         ListStyleType::None => None,
         ListStyleType::Disc => Some("• "),
         ListStyleType::Circle => Some("◦ "),
         ListStyleType::Square => Some("▪ "),
         ListStyleType::DisclosureOpen => Some("▾ "),
-        ListStyleType::DisclosureClosed => Some("‣ "),
+        ListStyleType::DisclosureClosed if writing_mode.is_bidi_ltr() => Some("▸ "),
+        ListStyleType::DisclosureClosed => Some("◂ "),
         ListStyleType::Decimal |
+        ListStyleType::DecimalLeadingZero |
         ListStyleType::LowerAlpha |
         ListStyleType::UpperAlpha |
         ListStyleType::ArabicIndic |
@@ -80,7 +83,9 @@ fn marker_string(style: &style_structs::List) -> Option<&'static str> {
         ListStyleType::Hiragana |
         ListStyleType::HiraganaIroha |
         ListStyleType::Katakana |
-        ListStyleType::KatakanaIroha => {
+        ListStyleType::KatakanaIroha |
+        ListStyleType::LowerRoman |
+        ListStyleType::UpperRoman => {
             // TODO: Implement support for counters.
             None
         },
@@ -25,6 +25,7 @@ use euclid::{Point2D, Rect, Scale, Size2D};
 use fnv::FnvHashMap;
 use fxhash::{FxHashMap, FxHashSet};
 use gfx::font_cache_thread::FontCacheThread;
+use gfx::font_template::UnicodeRanges;
 use gfx::{font, font_context};
 use gfx_traits::{node_id_from_scroll_id, Epoch};
 use histogram::Histogram;
@@ -100,7 +101,8 @@ use style::selector_parser::{PseudoElement, SnapshotMap};
 use style::servo::restyle_damage::ServoRestyleDamage;
 use style::shared_lock::{SharedRwLock, SharedRwLockReadGuard, StylesheetGuards};
 use style::stylesheets::{
-    DocumentStyleSheet, Origin, Stylesheet, StylesheetInDocument, UserAgentStylesheets,
+    DocumentStyleSheet, FontFaceRule, Origin, Stylesheet, StylesheetInDocument,
+    UserAgentStylesheets,
 };
 use style::stylist::Stylist;
 use style::thread_state::{self, ThreadState};
@@ -380,6 +382,13 @@ impl<'a, 'b: 'a> RwData<'a, 'b> {
     }
 }
 
+/// Returns the code points that the given `@font-face` rule is restricted to, if any.
+fn unicode_ranges(rule: &FontFaceRule) -> Option<UnicodeRanges> {
+    rule.unicode_range
+        .as_ref()
+        .map(|ranges| ranges.iter().map(|range| range.start..=range.end).collect())
+}
+
 fn add_font_face_rules(
     stylesheet: &Stylesheet,
     guard: &SharedRwLockReadGuard,
@@ -397,6 +406,7 @@ fn add_font_face_rules(
                 font_cache_thread.add_web_font(
                     font_face.family().clone(),
                     effective_sources,
+                    unicode_ranges(rule),
                     sender.clone(),
                 );
                 receiver.recv().unwrap();
@@ -410,6 +420,7 @@ fn add_font_face_rules(
                 font_cache_thread.add_web_font(
                     font_face.family().clone(),
                     effective_sources,
+                    unicode_ranges(rule),
                     (*font_cache_sender).clone(),
                 );
             }
@@ -558,6 +569,7 @@ impl LayoutThread {
             webrender_image_cache: self.webrender_image_cache.clone(),
             pending_images: Mutex::new(vec![]),
             registered_painters: &self.registered_painters,
+            statistics: Default::default(),
         }
     }
 
@@ -1707,6 +1719,8 @@ impl LayoutThread {
             root_flow.print("Post layout flow tree".to_owned());
         }
 
+        layout_context.statistics.report(&self.time_profiler_chan);
+
         self.generation.set(self.generation.get() + 1);
     }
 
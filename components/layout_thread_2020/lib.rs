@@ -25,6 +25,7 @@ use fnv::FnvHashMap;
 use fxhash::FxHashMap;
 use gfx::font_cache_thread::FontCacheThread;
 use gfx::font_context;
+use gfx::font_template::UnicodeRanges;
 use gfx_traits::{node_id_from_scroll_id, Epoch};
 use ipc_channel::ipc::{self, IpcReceiver, IpcSender};
 use ipc_channel::router::ROUTER;
@@ -84,7 +85,8 @@ use style::properties::PropertyId;
 use style::selector_parser::SnapshotMap;
 use style::shared_lock::{SharedRwLock, SharedRwLockReadGuard, StylesheetGuards};
 use style::stylesheets::{
-    DocumentStyleSheet, Origin, Stylesheet, StylesheetInDocument, UserAgentStylesheets,
+    DocumentStyleSheet, FontFaceRule, Origin, Stylesheet, StylesheetInDocument,
+    UserAgentStylesheets,
 };
 use style::stylist::Stylist;
 use style::thread_state::{self, ThreadState};
@@ -358,6 +360,13 @@ impl<'a, 'b: 'a> RwData<'a, 'b> {
     }
 }
 
+/// Returns the code points that the given `@font-face` rule is restricted to, if any.
+fn unicode_ranges(rule: &FontFaceRule) -> Option<UnicodeRanges> {
+    rule.unicode_range
+        .as_ref()
+        .map(|ranges| ranges.iter().map(|range| range.start..=range.end).collect())
+}
+
 fn add_font_face_rules(
     stylesheet: &Stylesheet,
     guard: &SharedRwLockReadGuard,
@@ -375,6 +384,7 @@ fn add_font_face_rules(
                 font_cache_thread.add_web_font(
                     font_face.family().clone(),
                     effective_sources,
+                    unicode_ranges(rule),
                     sender.clone(),
                 );
                 receiver.recv().unwrap();
@@ -388,6 +398,7 @@ fn add_font_face_rules(
                 font_cache_thread.add_web_font(
                     font_face.family().clone(),
                     effective_sources,
+                    unicode_ranges(rule),
                     (*font_cache_sender).clone(),
                 );
             }
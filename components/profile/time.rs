@@ -166,6 +166,8 @@ pub struct Profiler {
     pub last_msg: Option<ProfilerMsg>,
     trace: Option<TraceDump>,
     blocked_layout_queries: HashMap<String, u32>,
+    list_markers_laid_out: usize,
+    list_marker_font_context_invocations: usize,
 }
 
 impl Profiler {
@@ -247,6 +249,8 @@ impl Profiler {
             last_msg: None,
             trace: trace,
             blocked_layout_queries: HashMap::new(),
+            list_markers_laid_out: 0,
+            list_marker_font_context_invocations: 0,
         }
     }
 
@@ -272,7 +276,9 @@ impl Profiler {
                 self.find_or_insert(k, ms);
             },
             ProfilerMsg::Print => {
-                if let Some(ProfilerMsg::Time(..)) = self.last_msg {
+                if let Some(ProfilerMsg::Time(..)) | Some(ProfilerMsg::ListMarkers(..)) =
+                    self.last_msg
+                {
                     // only print if more data has arrived since the last printout
                     self.print_buckets();
                 }
@@ -289,6 +295,10 @@ impl Profiler {
             ProfilerMsg::BlockedLayoutQuery(url) => {
                 *self.blocked_layout_queries.entry(url).or_insert(0) += 1;
             },
+            ProfilerMsg::ListMarkers(laid_out, font_context_invocations) => {
+                self.list_markers_laid_out += laid_out;
+                self.list_marker_font_context_invocations += font_context_invocations;
+            },
             ProfilerMsg::Exit(chan) => {
                 self.print_buckets();
                 let _ = chan.send(());
@@ -355,6 +365,18 @@ impl Profiler {
                 for (url, count) in &self.blocked_layout_queries {
                     write!(file, "{}\t{}\n", url, count).unwrap();
                 }
+
+                write!(
+                    file,
+                    "_list markers laid out_\t_list marker font context invocations_\n"
+                )
+                .unwrap();
+                write!(
+                    file,
+                    "{}\t{}\n",
+                    self.list_markers_laid_out, self.list_marker_font_context_invocations
+                )
+                .unwrap();
             },
             Some(OutputOptions::Stdout(_)) => {
                 let stdout = io::stdout();
@@ -400,6 +422,19 @@ impl Profiler {
                     writeln!(&mut lock, "{}\t{}", url, count).unwrap();
                 }
                 writeln!(&mut lock, "").unwrap();
+
+                writeln!(
+                    &mut lock,
+                    "_list markers laid out_\t_list marker font context invocations_"
+                )
+                .unwrap();
+                writeln!(
+                    &mut lock,
+                    "{}\t{}",
+                    self.list_markers_laid_out, self.list_marker_font_context_invocations
+                )
+                .unwrap();
+                writeln!(&mut lock, "").unwrap();
             },
             None => { /* Do nothing if no output option has been set */ },
         };
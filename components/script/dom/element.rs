@@ -35,6 +35,7 @@ use selectors::sink::Push;
 use selectors::Element as SelectorsElement;
 use servo_arc::Arc;
 use servo_atoms::Atom;
+use servo_config::pref;
 use style::applicable_declarations::ApplicableDeclarationBlock;
 use style::attr::{AttrValue, LengthOrPercentageOrAuto};
 use style::context::QuirksMode;
@@ -54,8 +55,9 @@ use style::selector_parser::{
 use style::shared_lock::{Locked, SharedRwLock};
 use style::stylesheets::layer_rule::LayerOrder;
 use style::stylesheets::CssRuleType;
+use style::values::generics::counters::CounterPair;
 use style::values::generics::NonNegative;
-use style::values::{computed, specified, AtomIdent, AtomString, CSSFloat};
+use style::values::{computed, specified, AtomIdent, AtomString, CSSFloat, CustomIdent};
 use style::{dom_apis, thread_state, CaseSensitivityExt};
 use style_traits::dom::ElementState;
 use xml5ever::serialize as xmlSerialize;
@@ -114,8 +116,11 @@ use crate::dom::htmlimageelement::{HTMLImageElement, LayoutHTMLImageElementHelpe
 use crate::dom::htmlinputelement::{HTMLInputElement, LayoutHTMLInputElementHelpers};
 use crate::dom::htmllabelelement::HTMLLabelElement;
 use crate::dom::htmllegendelement::HTMLLegendElement;
+use crate::dom::htmllielement::{HTMLLIElement, HTMLLIElementLayoutHelpers};
 use crate::dom::htmllinkelement::HTMLLinkElement;
+use crate::dom::htmlmenuelement::HTMLMenuElement;
 use crate::dom::htmlobjectelement::HTMLObjectElement;
+use crate::dom::htmlolistelement::{default_list_style_type_for_lang, HTMLOListElement};
 use crate::dom::htmloptgroupelement::HTMLOptGroupElement;
 use crate::dom::htmloutputelement::HTMLOutputElement;
 use crate::dom::htmlselectelement::HTMLSelectElement;
@@ -128,6 +133,7 @@ use crate::dom::htmltablesectionelement::{
 };
 use crate::dom::htmltemplateelement::HTMLTemplateElement;
 use crate::dom::htmltextareaelement::{HTMLTextAreaElement, LayoutHTMLTextAreaElementHelpers};
+use crate::dom::htmlulistelement::HTMLUListElement;
 use crate::dom::mutationobserver::{Mutation, MutationObserver};
 use crate::dom::namednodemap::NamedNodeMap;
 use crate::dom::node::{
@@ -781,6 +787,54 @@ impl<'dom> LayoutElementHelpers<'dom> for LayoutDom<'dom, Element> {
             ))
         }
 
+        if self.is::<HTMLOListElement>() ||
+            self.is::<HTMLUListElement>() ||
+            self.is::<HTMLMenuElement>()
+        {
+            // https://html.spec.whatwg.org/multipage/#lists: lists reset `list-item`, so the
+            // items of a nested list start counting again.
+            hints.push(from_declaration(
+                shared_lock,
+                PropertyDeclaration::CounterReset(specified::CounterReset::new(vec![
+                    CounterPair {
+                        name: CustomIdent(Atom::from("list-item")),
+                        value: specified::Integer::new(0),
+                        is_reversed: false,
+                    },
+                ])),
+            ))
+        }
+
+        let ordinal_value = self
+            .downcast::<HTMLLIElement>()
+            .and_then(|this| this.get_value());
+
+        if let Some(ordinal_value) = ordinal_value {
+            // https://html.spec.whatwg.org/multipage/#lists
+            hints.push(from_declaration(
+                shared_lock,
+                PropertyDeclaration::CounterSet(specified::CounterSet::new(vec![CounterPair {
+                    name: CustomIdent(Atom::from("list-item")),
+                    value: specified::Integer::new(ordinal_value),
+                    is_reversed: false,
+                }])),
+            ))
+        }
+
+        if pref!(layout.list_numbers.lang_defaults) && self.is::<HTMLOListElement>() {
+            // Only the list's own `lang` counts, since a change to an ancestor's doesn't
+            // invalidate the list's presentational hints.
+            let lang = self
+                .get_attr_val_for_layout(&ns!(xml), &local_name!("lang"))
+                .or_else(|| self.get_attr_val_for_layout(&ns!(), &local_name!("lang")));
+            if let Some(list_style_type) = lang.and_then(default_list_style_type_for_lang) {
+                hints.push(from_declaration(
+                    shared_lock,
+                    PropertyDeclaration::ListStyleType(list_style_type),
+                ))
+            }
+        }
+
         let cellspacing = if let Some(this) = self.downcast::<HTMLTableElement>() {
             this.get_cellspacing()
         } else {
@@ -3,15 +3,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 use dom_struct::dom_struct;
-use html5ever::{local_name, LocalName, Prefix};
+use html5ever::{local_name, namespace_url, ns, LocalName, Prefix};
 use js::rust::HandleObject;
-use style::attr::AttrValue;
+use style::attr::{parse_integer, AttrValue};
 
+use crate::dom::attr::Attr;
 use crate::dom::bindings::codegen::Bindings::HTMLLIElementBinding::HTMLLIElementMethods;
 use crate::dom::bindings::inheritance::Castable;
-use crate::dom::bindings::root::DomRoot;
+use crate::dom::bindings::root::{DomRoot, LayoutDom};
 use crate::dom::bindings::str::DOMString;
 use crate::dom::document::Document;
+use crate::dom::element::{Element, LayoutElementHelpers};
 use crate::dom::htmlelement::HTMLElement;
 use crate::dom::node::Node;
 use crate::dom::virtualmethods::VirtualMethods;
@@ -55,11 +57,35 @@ impl HTMLLIElementMethods for HTMLLIElement {
     make_int_setter!(SetValue, "value");
 }
 
+pub trait HTMLLIElementLayoutHelpers {
+    fn get_value(self) -> Option<i32>;
+}
+
+impl HTMLLIElementLayoutHelpers for LayoutDom<'_, HTMLLIElement> {
+    // https://html.spec.whatwg.org/multipage/#ordinal-value
+    fn get_value(self) -> Option<i32> {
+        // A value that doesn't parse as an integer has no effect on the ordinal.
+        self.upcast::<Element>()
+            .get_attr_val_for_layout(&ns!(), &local_name!("value"))
+            .and_then(|value| parse_integer(value.chars()).ok())
+    }
+}
+
 impl VirtualMethods for HTMLLIElement {
     fn super_type(&self) -> Option<&dyn VirtualMethods> {
         Some(self.upcast::<HTMLElement>() as &dyn VirtualMethods)
     }
 
+    fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
+        if attr.local_name() == &local_name!("value") {
+            return true;
+        }
+
+        self.super_type()
+            .unwrap()
+            .attribute_affects_presentational_hints(attr)
+    }
+
     fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
         match name {
             &local_name!("value") => AttrValue::from_i32(value.into(), 0),
@@ -3,13 +3,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 use dom_struct::dom_struct;
-use html5ever::{LocalName, Prefix};
+use html5ever::{local_name, namespace_url, ns, LocalName, Prefix};
 use js::rust::HandleObject;
+use style::properties::longhands::list_style_type::SpecifiedValue as ListStyleType;
 
+use crate::dom::attr::Attr;
+use crate::dom::bindings::inheritance::Castable;
 use crate::dom::bindings::root::DomRoot;
 use crate::dom::document::Document;
 use crate::dom::htmlelement::HTMLElement;
 use crate::dom::node::Node;
+use crate::dom::virtualmethods::VirtualMethods;
 
 #[dom_struct]
 pub struct HTMLOListElement {
@@ -43,3 +47,34 @@ impl HTMLOListElement {
         )
     }
 }
+
+impl VirtualMethods for HTMLOListElement {
+    fn super_type(&self) -> Option<&dyn VirtualMethods> {
+        Some(self.upcast::<HTMLElement>() as &dyn VirtualMethods)
+    }
+
+    fn attribute_affects_presentational_hints(&self, attr: &Attr) -> bool {
+        if attr.local_name() == &local_name!("lang") {
+            return true;
+        }
+
+        self.super_type()
+            .unwrap()
+            .attribute_affects_presentational_hints(attr)
+    }
+}
+
+/// Returns the numbering `<ol>` uses by default for content in the given language, when the
+/// `layout.list_numbers.lang_defaults` preference is enabled. Author styles still take
+/// precedence, since this is applied as a presentational hint.
+pub fn default_list_style_type_for_lang(lang: &str) -> Option<ListStyleType> {
+    let primary_subtag = lang.split('-').next()?.to_ascii_lowercase();
+    Some(match &*primary_subtag {
+        "ar" => ListStyleType::ArabicIndic,
+        "bn" => ListStyleType::Bengali,
+        "fa" => ListStyleType::Persian,
+        "hi" | "mr" | "ne" => ListStyleType::Devanagari,
+        "th" => ListStyleType::Thai,
+        _ => return None,
+    })
+}
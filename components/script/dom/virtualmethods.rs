@@ -36,6 +36,7 @@ use crate::dom::htmllinkelement::HTMLLinkElement;
 use crate::dom::htmlmediaelement::HTMLMediaElement;
 use crate::dom::htmlmetaelement::HTMLMetaElement;
 use crate::dom::htmlobjectelement::HTMLObjectElement;
+use crate::dom::htmlolistelement::HTMLOListElement;
 use crate::dom::htmloptgroupelement::HTMLOptGroupElement;
 use crate::dom::htmloptionelement::HTMLOptionElement;
 use crate::dom::htmloutputelement::HTMLOutputElement;
@@ -223,6 +224,9 @@ pub fn vtable_for(node: &Node) -> &dyn VirtualMethods {
         NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLObjectElement)) => {
             node.downcast::<HTMLObjectElement>().unwrap() as &dyn VirtualMethods
         },
+        NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLOListElement)) => {
+            node.downcast::<HTMLOListElement>().unwrap() as &dyn VirtualMethods
+        },
         NodeTypeId::Element(ElementTypeId::HTMLElement(HTMLElementTypeId::HTMLOptGroupElement)) => {
             node.downcast::<HTMLOptGroupElement>().unwrap() as &dyn VirtualMethods
         },
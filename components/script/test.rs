@@ -60,6 +60,12 @@ pub mod size_of {
     }
 }
 
+pub mod olist {
+    pub use style::properties::longhands::list_style_type::SpecifiedValue as ListStyleType;
+
+    pub use crate::dom::htmlolistelement::default_list_style_type_for_lang;
+}
+
 pub mod srcset {
     pub use crate::dom::htmlimageelement::{parse_a_srcset_attribute, Descriptor, ImageSource};
 }
@@ -48,6 +48,10 @@ pub enum ProfilerMsg {
     /// Report a layout query that could not be processed immediately for a particular URL.
     BlockedLayoutQuery(String),
 
+    /// Report the number of list item markers one layout laid out, and the number of times it
+    /// used the font context to build or measure them.
+    ListMarkers(usize, usize),
+
     /// Tells the profiler to shut down.
     Exit(IpcSender<()>),
 }
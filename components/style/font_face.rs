@@ -24,7 +24,6 @@ use crate::values::specified::url::SpecifiedUrl;
 use crate::values::specified::Angle;
 #[cfg(feature = "gecko")]
 use crate::values::specified::NonNegativePercentage;
-#[cfg(feature = "gecko")]
 use cssparser::UnicodeRange;
 use cssparser::{
     AtRuleParser, CowRcStr, DeclarationParser, Parser, QualifiedRuleParser, RuleBodyItemParser,
@@ -837,5 +836,7 @@ font_face_descriptors! {
         "src" sources / mSrc: SourceList,
     ]
     optional descriptors = [
+        /// The ranges of code points outside of which this font face should not be used.
+        "unicode-range" unicode_range / mUnicodeRange: Vec<UnicodeRange>,
     ]
 }
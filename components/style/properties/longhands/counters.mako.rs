@@ -42,7 +42,8 @@ ${helpers.predefined_type(
 ${helpers.predefined_type(
     "counter-set",
     "CounterSet",
-    engines="gecko",
+    engines="gecko servo",
+    servo_pref="layout.legacy_layout",
     initial_value="Default::default()",
     animation_value_type="discrete",
     spec="https://drafts.csswg.org/css-lists-3/#propdef-counter-set",
@@ -19,18 +19,22 @@ ${helpers.single_keyword(
 
 // TODO(pcwalton): Implement the full set of counter styles per CSS-COUNTER-STYLES [1] 6.1:
 //
-//     decimal-leading-zero, armenian, upper-armenian, lower-armenian, georgian, lower-roman,
-//     upper-roman
+//     armenian, upper-armenian, lower-armenian, georgian
+//
+// TODO: Support anonymous counter styles (`symbols()`, CSS-COUNTER-STYLES [1] 6.2). Servo's
+// `list-style-type` is a plain keyword, so it cannot carry the symbol list; this needs the
+// Gecko `ListStyleType`/`CounterStyle` representation and generated-content support for the
+// cyclic, fixed, symbolic, alphabetic and numeric systems.
 //
 // [1]: http://dev.w3.org/csswg/css-counter-styles/
 % if engine == "servo":
     ${helpers.single_keyword(
         "list-style-type",
         """disc none circle square disclosure-open disclosure-closed
-           decimal lower-alpha upper-alpha arabic-indic bengali cambodian cjk-decimal devanagari
-           gujarati gurmukhi kannada khmer lao malayalam mongolian myanmar oriya persian telugu
-           thai tibetan cjk-earthly-branch cjk-heavenly-stem lower-greek hiragana hiragana-iroha
-           katakana katakana-iroha
+           decimal decimal-leading-zero lower-alpha upper-alpha arabic-indic bengali cambodian
+           cjk-decimal devanagari gujarati gurmukhi kannada khmer lao malayalam mongolian myanmar
+           oriya persian telugu thai tibetan cjk-earthly-branch cjk-heavenly-stem lower-greek
+           hiragana hiragana-iroha katakana katakana-iroha lower-roman upper-roman
         """,
         engines="servo",
         animation_value_type="discrete",
@@ -0,0 +1,23 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use script::test::olist::{default_list_style_type_for_lang, ListStyleType};
+
+#[test]
+fn arabic_uses_arabic_indic_digits() {
+    assert_eq!(
+        default_list_style_type_for_lang("ar"),
+        Some(ListStyleType::ArabicIndic)
+    );
+    assert_eq!(
+        default_list_style_type_for_lang("ar-EG"),
+        Some(ListStyleType::ArabicIndic)
+    );
+}
+
+#[test]
+fn unknown_or_empty_lang_has_no_default() {
+    assert_eq!(default_list_style_type_for_lang("en"), None);
+    assert_eq!(default_list_style_type_for_lang(""), None);
+}
@@ -0,0 +1,46 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use style_traits::ToCss;
+
+use crate::parsing::parse;
+
+#[test]
+fn test_content_counter_style() {
+    use style::properties::longhands::content;
+
+    assert_roundtrip_with_context!(content::parse, "counter(x)");
+    assert_roundtrip_with_context!(content::parse, "counter(x, decimal)", "counter(x)");
+    assert_roundtrip_with_context!(content::parse, "counter(x, upper-roman)");
+    assert_roundtrip_with_context!(content::parse, "counter(x, lower-roman)");
+    assert_roundtrip_with_context!(content::parse, "counter(x, decimal-leading-zero)");
+    assert_roundtrip_with_context!(content::parse, r#"counters(x, ".", upper-roman)"#);
+
+    assert!(parse(content::parse, "counter(x, roman)").is_err());
+}
+
+#[test]
+fn test_counter_set() {
+    use style::properties::longhands::counter_set;
+
+    assert_roundtrip_with_context!(counter_set::parse, "none");
+    assert_roundtrip_with_context!(counter_set::parse, "item 10");
+    assert_roundtrip_with_context!(counter_set::parse, "item", "item 0");
+    assert_roundtrip_with_context!(counter_set::parse, "list-item 3 other -1");
+
+    assert!(parse(counter_set::parse, "10").is_err());
+}
+
+#[test]
+fn test_counter_reset() {
+    use style::properties::longhands::counter_reset;
+
+    assert_roundtrip_with_context!(counter_reset::parse, "none");
+    assert_roundtrip_with_context!(counter_reset::parse, "a 1 b 2");
+    assert_roundtrip_with_context!(counter_reset::parse, "a b 2", "a 0 b 2");
+
+    let reset = parse(counter_reset::parse, "a 1 b 2").unwrap();
+    let names: Vec<_> = reset.iter().map(|pair| pair.name.0.to_string()).collect();
+    assert_eq!(names, ["a", "b"]);
+}
@@ -117,6 +117,7 @@ mod background;
 mod border;
 mod box_;
 mod column;
+mod counters;
 mod effects;
 mod image;
 mod inherited_text;